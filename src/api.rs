@@ -4,11 +4,37 @@
 /// cover more (e.g. Projects, Organizations, etc.). It is sufficient for most asset-related operations
 /// such as file upload/download.
 use crate::{
-    client::Client,
-    configuration::Configuration,
-    model::{Asset, AssetIdentity, AssetStatus, MetadataEntry},
+    checksum::ChecksumAlgorithm,
+    client::{AssetCreateRequest, Client, ClientError},
+    configuration::{Configuration, DEFAULT_PRIMARY_TYPE},
+    model::{
+        Asset, AssetDiff, AssetFile, AssetFileUpdate, AssetFileUpdateResult, AssetIdentity,
+        AssetStatus, AssetType, AssetUpdate, AssetUpdateResult, AssetVersion, Dataset,
+        DatasetUpdate, DatasetUpdateResult, DuplicateFile, DuplicateGroup, FileChange,
+        FileChangeStatus, FileUploadResult, FileVerification, LocalDiffApplyResult, MetadataChange,
+        MetadataDefinition, MetadataDefinitionUpdate, MetadataEntry, MetadataFieldType,
+        MetadataFormat, MetadataImportResult, MetadataImportStatus, MetadataMode, MetadataSchema,
+        MetadataSchemaAction, MetadataSchemaDrift, MetadataValueError, ModelAnalysisResult,
+        PreviewRenderResult, SidecarMetadata, StatusTransition, StatusTransitionResult,
+        StatusTransitionStatus, ThumbnailGenerationResult, ThumbnailGenerationStatus,
+        Transformation, TransformationRequest, TransformationStatus, UploadStatus,
+        ValidationReport, VerificationStatus, LATEST_VERSION,
+    },
+    preview::{self, Mesh, PreviewError},
+    stats::ModelStats,
+    validation,
+};
+use calamine::Reader;
+use futures::{stream, StreamExt};
+use serde_json::Value;
+use std::{
+    cell::RefCell,
+    collections::{hash_map::Entry, BTreeMap, BTreeSet, HashMap},
+    fs::{self, File},
+    io::BufRead,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
 };
-use std::{cell::RefCell, collections::HashMap, fs::File, path::PathBuf};
 use thiserror::Error;
 
 /// Wrapper error for all errors that may occur while
@@ -29,6 +55,333 @@ pub enum ApiError {
     CsvParse(#[from] csv::Error),
     #[error("asset not found")]
     AssetNotFound,
+    #[error("dataset {0} not found")]
+    DatasetNotFound(String),
+    #[error("several local files would be uploaded as {0}")]
+    DuplicateFilePath(String),
+    #[error("failed to replace file {0}, its new content is kept as {1}")]
+    ReplaceFailed(String, String),
+    #[error("file {0} not found")]
+    FileNotFound(String),
+    #[error("JSON format parsing error")]
+    JsonParse(#[from] serde_json::Error),
+    #[error("YAML format parsing error")]
+    YamlParse(#[from] serde_yaml::Error),
+    #[error("unsupported file format: {0}")]
+    UnsupportedFileFormat(String),
+    #[error("a name can only be set on a single asset, but {0} assets are selected")]
+    NameForMultipleAssets(usize),
+    #[error("sidecar files have different values for {0}")]
+    SidecarConflict(String),
+    #[error("{0}")]
+    InvalidMetadataValue(#[from] MetadataValueError),
+    #[error("metadata field {0} not found")]
+    MetadataFieldNotFound(String),
+    #[error("spreadsheet parsing error")]
+    SpreadsheetParse(#[from] calamine::Error),
+    #[error("invalid manifest: {0}")]
+    InvalidManifest(String),
+    #[error("invalid metadata file: {0}")]
+    InvalidMetadataFile(String),
+    #[error("asset name is not provided")]
+    MissingAssetName,
+    #[error("invalid asset identity: {0}")]
+    InvalidAssetIdentity(String),
+    #[error("no files in dataset {0}")]
+    NoFilesInDataset(String),
+    #[error("transformation {0} not found")]
+    TransformationNotFound(String),
+    #[error("transformation {0} did not finish before the timeout")]
+    TransformationTimeout(String),
+    #[error("preview error")]
+    PreviewError(#[from] PreviewError),
+    #[error("no supported geometry file found")]
+    NoGeometryFile,
+    #[error("data file validation failed:\n{0}")]
+    ValidationFailed(String),
+    #[error("unknown asset status {0}")]
+    UnknownAssetStatus(String),
+    #[error(
+        "cannot change asset status from {0} to {1}. Allowed transitions: {}",
+        AssetStatus::workflow()
+    )]
+    InvalidStatusTransition(AssetStatus, AssetStatus),
+}
+
+/// Maximum number of concurrent requests for operations on many assets.
+const DEFAULT_CONCURRENCY: usize = 8;
+/// Time between two status requests while waiting for a transformation.
+const TRANSFORMATION_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// Suffix of the temporary file holding the new content while a file is replaced.
+const REPLACEMENT_SUFFIX: &str = ".uamcli-replace";
+/// Name of the dataset holding the preview images of an asset.
+pub const PREVIEW_DATASET: &str = "Preview";
+/// Default maximum time to wait for a transformation.
+pub const DEFAULT_TRANSFORMATION_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Name of the dataset Unity creates for the source files of an asset.
+pub const SOURCE_DATASET_NAME: &str = "Source";
+
+/// Column with the asset ID in wide metadata tables.
+pub const METADATA_COLUMN_ASSET_ID: &str = "AssetId";
+/// Column with the asset version in wide metadata tables.
+pub const METADATA_COLUMN_ASSET_VERSION: &str = "AssetVersion";
+/// Column with the asset name in wide metadata tables.
+pub const METADATA_COLUMN_ASSET_NAME: &str = "AssetName";
+
+/// Default separator for the keys of nested metadata values.
+pub const DEFAULT_METADATA_KEY_SEPARATOR: &str = ".";
+
+/// Sidecar files are named after the data file with this suffix and one of the metadata file extensions,
+/// e.g. part.step.meta.json.
+pub const SIDECAR_SUFFIX: &str = ".meta";
+/// Sidecar file extensions in the order they are looked up.
+pub const SIDECAR_EXTENSIONS: [&str; 4] = ["json", "yaml", "yml", "csv"];
+
+/// Criteria for selecting assets for bulk operations.
+///
+/// If identities are provided, only those assets are considered. Otherwise, the project is searched
+/// by the asset name. All other criteria are applied as filters on the result.
+#[derive(Debug, Default, Clone)]
+pub struct AssetSelection {
+    /// Explicit list of assets.
+    pub identities: Vec<AssetIdentity>,
+    /// Only assets whose name contains this value (case-insensitive).
+    pub asset_name: Option<String>,
+    /// Only assets with this status.
+    pub status: Option<AssetStatus>,
+    /// Only assets with a preview file (thumbnail).
+    pub has_thumbnail: bool,
+    /// Only assets with a non-empty value for all these metadata fields.
+    pub metadata_keys: Vec<String>,
+}
+
+impl AssetSelection {
+    /// Returns true if the asset matches the filters.
+    pub fn matches(&self, asset: &Asset) -> bool {
+        let name_matches = match &self.asset_name {
+            Some(name) => asset
+                .name()
+                .to_lowercase()
+                .contains(name.to_lowercase().as_str()),
+            None => true,
+        };
+        let status_matches = match &self.status {
+            Some(status) => &asset.status() == status,
+            None => true,
+        };
+        let thumbnail_matches =
+            !self.has_thumbnail || asset.preview_file().is_some_and(|p| !p.is_empty());
+        let metadata = asset.metadata().flatten().unwrap_or_default();
+        let metadata_matches = self.metadata_keys.iter().all(|k| {
+            metadata
+                .get(k)
+                .is_some_and(|v| !Api::is_empty_metadata_value(v))
+        });
+
+        name_matches && status_matches && thumbnail_matches && metadata_matches
+    }
+}
+
+/// Optional settings for a new asset.
+#[derive(Debug, Default, Clone)]
+pub struct AssetCreateOptions {
+    /// The asset primary type. If None, it is detected from the file extensions.
+    pub primary_type: Option<String>,
+    /// Tags to be set on the asset.
+    pub tags: Vec<String>,
+    /// Metadata field values to be set on the asset.
+    pub metadata: HashMap<String, Value>,
+    /// If true, the asset will be published after creation.
+    pub publish: bool,
+    /// The algorithm for the file checksums. If None, the configured one will be used.
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
+    /// If true, sidecar metadata files next to the data files are applied to the asset.
+    pub sidecar: bool,
+    /// If true, the statistics of the 3D model data files are stored as metadata.
+    /// Values provided explicitly take precedence over the extracted ones.
+    pub extract_stats: bool,
+    /// If true, the data files are validated before the asset is created. See validate_files.
+    pub validate: bool,
+    /// If true, the files referenced by the data files (e.g. OBJ materials, textures) are uploaded as well.
+    pub include_references: bool,
+    /// The dataset (name or ID) the data files are uploaded to. If None, the Source dataset is used.
+    /// A dataset that does not exist is created with the primary type of the asset.
+    pub dataset: Option<String>,
+}
+
+/// Requested changes for existing assets.
+#[derive(Debug, Default, Clone)]
+pub struct AssetUpdateOptions {
+    /// The new asset name.
+    pub name: Option<String>,
+    /// The new asset description.
+    pub description: Option<String>,
+    /// Tags to be added to the asset.
+    pub add_tags: Vec<String>,
+    /// Tags to be removed from the asset.
+    pub remove_tags: Vec<String>,
+    /// The new asset primary type.
+    pub primary_type: Option<String>,
+}
+
+impl AssetUpdateOptions {
+    /// Returns the fields of the asset that differ from the requested state.
+    ///
+    /// Parameters:
+    ///
+    /// * asset - the current state of the asset
+    pub fn changes(&self, asset: &Asset) -> AssetUpdate {
+        let mut changes = AssetUpdate::default();
+
+        if let Some(name) = &self.name {
+            if !asset.name().eq(name) {
+                changes.name = Some(name.to_owned());
+            }
+        }
+
+        if let Some(description) = &self.description {
+            if asset.description().as_ref() != Some(description) {
+                changes.description = Some(description.to_owned());
+            }
+        }
+
+        if let Some(primary_type) = &self.primary_type {
+            if asset.primary_type() != AssetType::from(primary_type.as_str()) {
+                changes.primary_type = Some(primary_type.to_owned());
+            }
+        }
+
+        changes.tags = apply_tag_changes(
+            &asset.tags().unwrap_or_default(),
+            &self.add_tags,
+            &self.remove_tags,
+        );
+
+        changes
+    }
+}
+
+/// Returns the tags after removing and adding the given tags, or None if they are unchanged.
+///
+/// Parameters:
+///
+/// * existing - the current tags
+/// * add - tags to be added, unless already present
+/// * remove - tags to be removed
+fn apply_tag_changes(
+    existing: &[String],
+    add: &[String],
+    remove: &[String],
+) -> Option<Vec<String>> {
+    let mut tags: Vec<String> = existing
+        .iter()
+        .filter(|t| !remove.contains(t))
+        .cloned()
+        .collect();
+    for tag in add.iter() {
+        if !tags.contains(tag) {
+            tags.push(tag.to_owned());
+        }
+    }
+    (tags != existing).then_some(tags)
+}
+
+/// Requested changes for an existing dataset.
+#[derive(Debug, Default, Clone)]
+pub struct DatasetUpdateOptions {
+    /// The new dataset name.
+    pub name: Option<String>,
+    /// The new dataset description.
+    pub description: Option<String>,
+    /// Tags to be added to the dataset.
+    pub add_tags: Vec<String>,
+    /// Tags to be removed from the dataset.
+    pub remove_tags: Vec<String>,
+    /// The new dataset primary type.
+    pub primary_type: Option<String>,
+}
+
+impl DatasetUpdateOptions {
+    /// Returns the fields of the dataset that differ from the requested state.
+    ///
+    /// Parameters:
+    ///
+    /// * dataset - the current state of the dataset
+    pub fn changes(&self, dataset: &Dataset) -> DatasetUpdate {
+        let mut changes = DatasetUpdate::default();
+
+        if let Some(name) = &self.name {
+            if !dataset.name().eq(name) {
+                changes.name = Some(name.to_owned());
+            }
+        }
+
+        if let Some(description) = &self.description {
+            if dataset.description().as_ref() != Some(description) {
+                changes.description = Some(description.to_owned());
+            }
+        }
+
+        if let Some(primary_type) = &self.primary_type {
+            if dataset.primary_type().as_ref() != Some(primary_type) {
+                changes.primary_type = Some(primary_type.to_owned());
+            }
+        }
+
+        changes.tags = apply_tag_changes(
+            &dataset.tags().unwrap_or_default(),
+            &self.add_tags,
+            &self.remove_tags,
+        );
+
+        changes
+    }
+}
+
+/// Requested changes for an existing file.
+#[derive(Debug, Default, Clone)]
+pub struct AssetFileUpdateOptions {
+    /// The new file description.
+    pub description: Option<String>,
+    /// Tags to be added to the file.
+    pub add_tags: Vec<String>,
+    /// Tags to be removed from the file.
+    pub remove_tags: Vec<String>,
+    /// Metadata field values to be set on the file. Other fields are left unchanged.
+    pub metadata: HashMap<String, Value>,
+}
+
+impl AssetFileUpdateOptions {
+    /// Returns the fields of the file that differ from the requested state.
+    ///
+    /// Parameters:
+    ///
+    /// * file - the current state of the file
+    pub fn changes(&self, file: &AssetFile) -> AssetFileUpdate {
+        let mut changes = AssetFileUpdate::default();
+
+        if let Some(description) = &self.description {
+            if file.description().as_ref() != Some(description) {
+                changes.description = Some(description.to_owned());
+            }
+        }
+
+        changes.tags = apply_tag_changes(
+            &file.tags().unwrap_or_default(),
+            &self.add_tags,
+            &self.remove_tags,
+        );
+
+        let current_metadata = file.metadata().unwrap_or_default();
+        let mut metadata = current_metadata.clone();
+        metadata.extend(self.metadata.clone());
+        if metadata != current_metadata {
+            changes.metadata = Some(metadata);
+        }
+
+        changes
+    }
 }
 
 /// API client wrapper.
@@ -116,28 +469,185 @@ impl Api {
 
     /// Creates a new asset and uploads related files.
     ///
+    /// Any metadata fields that are not yet defined in the organization are registered
+    /// before the asset is created, so that the asset is created with its tags and metadata in one request.
+    ///
+    /// If sidecar files are enabled, the name, description, tags and metadata found in the sidecar files
+    /// of the data files are applied as well. Values provided explicitly take precedence over the sidecar
+    /// files. Tags are combined, but sidecar files with different values for the name, the description or
+    /// the same metadata field are rejected.
+    ///
     /// Parameters:
     ///
-    /// * name - unique asset name as it would apper in the Asset Manager UI
+    /// * name - unique asset name as it would apper in the Asset Manager UI. If None, the name from a
+    ///   sidecar file is used
     /// * description - asset human-readable description
     /// * data_files - list of PathBuff references for files to be uploaded
+    /// * options - primary type, tags, metadata and other optional settings
     pub async fn create_asset(
         &mut self,
-        name: String,
+        name: Option<String>,
         description: Option<String>,
         data_files: Vec<&PathBuf>,
-        publish: bool,
+        options: AssetCreateOptions,
     ) -> Result<AssetIdentity, ApiError> {
         self.init().await?;
+        let mut name = name;
+        let mut description = description;
+        let mut data_files = data_files;
+        let mut options = options;
+
+        if options.sidecar {
+            let sidecars: Vec<PathBuf> = data_files
+                .iter()
+                .filter_map(|f| Self::sidecar_path(f))
+                .collect();
+            // sidecar files provided as data files are not uploaded
+            data_files.retain(|f| !sidecars.iter().any(|s| s == *f));
+
+            let mut combined = SidecarMetadata::default();
+            for path in &sidecars {
+                log::trace!("Reading sidecar file {}...", path.display());
+                let sidecar = Self::read_sidecar(path)?;
+                combined.name = Self::combine_sidecar_value("name", combined.name, sidecar.name)?;
+                combined.description = Self::combine_sidecar_value(
+                    "description",
+                    combined.description,
+                    sidecar.description,
+                )?;
+                for tag in sidecar.tags {
+                    if !combined.tags.contains(&tag) {
+                        combined.tags.push(tag);
+                    }
+                }
+                for (key, value) in sidecar.metadata {
+                    let existing = combined.metadata.remove(&key);
+                    if let Some(value) = Self::combine_sidecar_value(&key, existing, Some(value))? {
+                        combined.metadata.insert(key, value);
+                    }
+                }
+            }
+
+            name = name.or(combined.name);
+            description = description.or(combined.description);
+            for tag in combined.tags {
+                if !options.tags.contains(&tag) {
+                    options.tags.push(tag);
+                }
+            }
+            for (key, value) in combined.metadata {
+                options.metadata.entry(key).or_insert(value);
+            }
+        }
+
+        let name = name.ok_or(ApiError::MissingAssetName)?;
+
+        let report;
+        if options.validate {
+            report = self.validate_files(&data_files, options.include_references);
+            for issue in &report.issues {
+                log::warn!("{}", issue);
+            }
+            if !report.is_valid() {
+                let errors: Vec<String> = report.errors().map(|e| e.to_string()).collect();
+                return Err(ApiError::ValidationFailed(errors.join("\n")));
+            }
+            data_files = report.files.iter().collect();
+        }
+
+        let mut stats_fields: Vec<String> = vec![];
+        if options.extract_stats {
+            let files: Vec<&Path> = data_files.iter().map(|f| f.as_path()).collect();
+            match Self::model_stats(&files)? {
+                Some(stats) => {
+                    for (key, value) in stats.metadata() {
+                        if let Entry::Vacant(entry) = options.metadata.entry(key) {
+                            stats_fields.push(entry.key().to_owned());
+                            entry.insert(value);
+                        }
+                    }
+                }
+                None => log::warn!("No 3D model file to extract the statistics from"),
+            }
+        }
+
         log::trace!("Creating asset {}...", name.to_owned());
+        let checksum_algorithm = options
+            .checksum_algorithm
+            .unwrap_or_else(|| self.configuration.borrow().checksum_algorithm());
+        let primary_type = options
+            .primary_type
+            .unwrap_or_else(|| self.detect_primary_type(&data_files));
+        log::trace!("Asset primary type is {}", primary_type);
         match &self.client {
             Some(client) => {
-                let id = client.create_asset(name, description, data_files).await?;
+                let values: HashMap<String, Value> = options
+                    .metadata
+                    .into_iter()
+                    .filter(|(_, v)| !Self::is_empty_metadata_value(v))
+                    .collect();
+                let mut definitions = Self::metadata_definitions(client, values.keys()).await?;
+                Self::register_metadata_definitions(client, &mut definitions, |n| {
+                    if stats_fields.contains(n) {
+                        (
+                            ModelStats::field_type(n).unwrap_or(MetadataFieldType::Text),
+                            None,
+                        )
+                    } else {
+                        Self::default_field(values.get(n))
+                    }
+                })
+                .await?;
+                let metadata = Self::coerce_metadata(&definitions, values)?;
+
+                let tags = if options.tags.is_empty() {
+                    None
+                } else {
+                    Some(options.tags)
+                };
+                let metadata = if metadata.is_empty() {
+                    None
+                } else {
+                    Some(metadata)
+                };
+                let dataset = options
+                    .dataset
+                    .filter(|d| !d.eq_ignore_ascii_case(SOURCE_DATASET_NAME));
+                let dataset_type = primary_type.to_owned();
+                let request =
+                    AssetCreateRequest::new(name, description, primary_type, tags, metadata);
+
+                let id = match &dataset {
+                    Some(_) => {
+                        client
+                            .create_asset(request, vec![], &checksum_algorithm)
+                            .await?
+                    }
+                    None => {
+                        client
+                            .create_asset(request, data_files.clone(), &checksum_algorithm)
+                            .await?
+                    }
+                };
+
+                if let Some(dataset) = dataset {
+                    let dataset_id =
+                        Self::find_or_create_dataset(client, &id, &dataset, dataset_type).await?;
+                    for path in &data_files {
+                        client
+                            .upload_file(&id, &dataset_id, path, &checksum_algorithm)
+                            .await?;
+                    }
+                }
 
-                if publish {
-                    self.set_asset_status(&id, &AssetStatus::InReview).await?;
-                    self.set_asset_status(&id, &AssetStatus::Approved).await?;
-                    self.set_asset_status(&id, &AssetStatus::Published).await?;
+                if options.publish {
+                    // a new asset is always created as a draft
+                    for status in AssetStatus::Draft
+                        .path_to(&AssetStatus::Published)
+                        .unwrap_or_default()
+                    {
+                        self.set_asset_status(&id, &status).await?;
+                    }
                 }
 
                 Ok(id)
@@ -146,6 +656,110 @@ impl Api {
         }
     }
 
+    /// Updates the name, description, tags and primary type of one or more assets.
+    ///
+    /// The current state of each asset is read first and only the fields that differ
+    /// from the requested state are sent to Unity. Assets with no changes are not updated.
+    /// A failing asset does not stop the others; its result carries the error.
+    ///
+    /// Parameters:
+    ///
+    /// * identities - the assets to be updated
+    /// * options - the requested changes. A name can only be set if a single asset is selected
+    pub async fn update_assets(
+        &mut self,
+        identities: Vec<AssetIdentity>,
+        options: &AssetUpdateOptions,
+    ) -> Result<Vec<AssetUpdateResult>, ApiError> {
+        if options.name.is_some() && identities.len() > 1 {
+            return Err(ApiError::NameForMultipleAssets(identities.len()));
+        }
+        self.init().await?;
+        log::trace!("Updating {} asset(s)...", identities.len());
+
+        match &self.client {
+            Some(client) => {
+                let results: Vec<AssetUpdateResult> = stream::iter(identities)
+                    .map(|identity| async move {
+                        let mut changes = AssetUpdate::default();
+                        let result: Result<bool, ApiError> = async {
+                            let asset = client
+                                .get_asset(&identity)
+                                .await?
+                                .ok_or(ApiError::AssetNotFound)?;
+                            changes = options.changes(&asset);
+                            if changes.is_empty() {
+                                log::trace!("No changes for asset {}", identity.id());
+                                return Ok(false);
+                            }
+                            client.patch_asset(&identity, &changes).await?;
+                            Ok(true)
+                        }
+                        .await;
+
+                        match result {
+                            Ok(updated) => AssetUpdateResult {
+                                asset: identity,
+                                changes,
+                                updated,
+                                error: None,
+                            },
+                            Err(e) => AssetUpdateResult {
+                                asset: identity,
+                                changes,
+                                updated: false,
+                                error: Some(e.to_string()),
+                            },
+                        }
+                    })
+                    .buffered(DEFAULT_CONCURRENCY)
+                    .collect()
+                    .await;
+
+                Ok(results)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Returns the primary type mapped to the extension of the first data file that has a mapping.
+    /// If none of the files has a known extension, the default primary type is returned.
+    ///
+    /// Parameters:
+    ///
+    /// * data_files - list of the files to be uploaded
+    pub fn detect_primary_type(&self, data_files: &[&PathBuf]) -> String {
+        let configuration = self.configuration.borrow();
+        data_files
+            .iter()
+            .find_map(|path| configuration.primary_type_for(path))
+            .unwrap_or(DEFAULT_PRIMARY_TYPE.to_string())
+    }
+
+    /// Validates data files before upload and returns the found issues.
+    ///
+    /// The files must be readable and have a known extension (mapped to a primary type or usually
+    /// uploaded with 3D models). STL, OBJ, PLY and glTF files are parsed and the files they
+    /// reference (material libraries, textures, buffers) must exist and be among the data files.
+    ///
+    /// Parameters:
+    ///
+    /// * data_files - the data files
+    /// * include_references - if true, the referenced files are added to the data files instead of being reported
+    pub fn validate_files(
+        &self,
+        data_files: &[&PathBuf],
+        include_references: bool,
+    ) -> ValidationReport {
+        let configuration = self.configuration.borrow();
+        let files: Vec<&Path> = data_files.iter().map(|f| f.as_path()).collect();
+        validation::validate_files(
+            &files,
+            |path| configuration.primary_type_for(path).is_some(),
+            include_references,
+        )
+    }
+
     pub async fn delete_asset(&mut self, asset_ids: Vec<String>) -> Result<(), ApiError> {
         self.init().await?;
         log::trace!("Deleting asset {}...", asset_ids.join(","));
@@ -199,6 +813,231 @@ impl Api {
         }
     }
 
+    /// Moves an asset to the target status through the shortest sequence of allowed transitions
+    /// and returns the statuses that were set.
+    ///
+    /// The path is computed from the current status before any change is made, so an invalid
+    /// transition is rejected without modifying the asset.
+    ///
+    /// Parameters:
+    /// * identity: reference to the asset's identity
+    /// * target: the desired status
+    pub async fn transition_asset_status(
+        &mut self,
+        identity: &AssetIdentity,
+        target: &AssetStatus,
+    ) -> Result<StatusTransition, ApiError> {
+        self.init().await?;
+        log::trace!(
+            "Changing status of asset {} to {}...",
+            identity.id(),
+            target
+        );
+        match &self.client {
+            Some(client) => {
+                let asset = match client.get_asset(identity).await? {
+                    Some(asset) => asset,
+                    None => return Err(ApiError::AssetNotFound),
+                };
+
+                Self::apply_status_transition(client, &asset, target, false).await
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Moves all selected assets to the target status and returns the result for each asset.
+    ///
+    /// The assets are processed concurrently. A failure for one asset does not stop the others.
+    ///
+    /// Parameters:
+    /// * selection: the assets to change
+    /// * target: the desired status
+    /// * dry_run: if true, the transitions are only computed, but not applied
+    pub async fn transition_assets_status(
+        &mut self,
+        selection: &AssetSelection,
+        target: &AssetStatus,
+        dry_run: bool,
+    ) -> Result<Vec<StatusTransitionResult>, ApiError> {
+        let assets = self.select_assets(selection).await?;
+        log::trace!(
+            "Changing status of {} asset(s) to {}...",
+            assets.len(),
+            target
+        );
+
+        match &self.client {
+            Some(client) => {
+                let results = stream::iter(assets)
+                    .map(|asset| async move {
+                        let result =
+                            Self::apply_status_transition(client, &asset, target, dry_run).await;
+                        let (path, status, error) = match result {
+                            Ok(transition) if transition.path.is_empty() => {
+                                (vec![], StatusTransitionStatus::Unchanged, None)
+                            }
+                            Ok(transition) if dry_run => {
+                                (transition.path, StatusTransitionStatus::Pending, None)
+                            }
+                            Ok(transition) => {
+                                (transition.path, StatusTransitionStatus::Changed, None)
+                            }
+                            Err(e) => (vec![], StatusTransitionStatus::Failed, Some(e.to_string())),
+                        };
+
+                        StatusTransitionResult {
+                            asset: asset.identity(),
+                            name: asset.name(),
+                            from: asset.status(),
+                            path,
+                            status,
+                            error,
+                        }
+                    })
+                    .buffered(DEFAULT_CONCURRENCY)
+                    .collect::<Vec<StatusTransitionResult>>()
+                    .await;
+
+                Ok(results)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Computes the shortest path from the current status of the asset to the target
+    /// status and applies it unless it is a dry run.
+    async fn apply_status_transition(
+        client: &Client,
+        asset: &Asset,
+        target: &AssetStatus,
+        dry_run: bool,
+    ) -> Result<StatusTransition, ApiError> {
+        let identity = asset.identity();
+        let from = asset.status();
+        if let AssetStatus::Unknown(name) = from {
+            return Err(ApiError::UnknownAssetStatus(name));
+        }
+        let path = match from.path_to(target) {
+            Some(path) => path,
+            None => return Err(ApiError::InvalidStatusTransition(from, target.clone())),
+        };
+
+        if !dry_run {
+            for status in &path {
+                log::trace!("Setting status {} for asset {}...", status, identity.id());
+                client.set_asset_status(&identity, status).await?;
+            }
+        }
+
+        Ok(StatusTransition {
+            asset: identity,
+            from,
+            path,
+        })
+    }
+
+    /// Returns the assets matching the selection.
+    ///
+    /// Parameters:
+    /// * selection: the selection criteria
+    pub async fn select_assets(
+        &mut self,
+        selection: &AssetSelection,
+    ) -> Result<Vec<Asset>, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let assets: Vec<Asset> = if selection.identities.is_empty() {
+                    client
+                        .search_asset(None, selection.asset_name.to_owned())
+                        .await?
+                } else {
+                    let assets: Vec<Result<Asset, ApiError>> =
+                        stream::iter(selection.identities.iter())
+                            .map(|identity| async move {
+                                match client.get_asset(identity).await? {
+                                    Some(asset) => Ok(asset),
+                                    None => Err(ApiError::AssetNotFound),
+                                }
+                            })
+                            .buffered(DEFAULT_CONCURRENCY)
+                            .collect()
+                            .await;
+                    assets
+                        .into_iter()
+                        .collect::<Result<Vec<Asset>, ApiError>>()?
+                };
+
+                Ok(assets
+                    .into_iter()
+                    .filter(|a| selection.matches(a))
+                    .collect())
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Reads a list of asset identities.
+    ///
+    /// Each non-empty line is either a JSON object (NDJSON), a JSON array of objects or an asset ID
+    /// optionally followed by the version, separated by a comma or whitespace. Lines starting with #
+    /// are ignored. A JSON object is
+    /// either an asset identity ({"id": ..., "version": ...}) or an object with the identity in the
+    /// "identity" or "asset" key, such as the assets printed by the search command.
+    ///
+    /// Parameters:
+    /// * reader: the input
+    /// * default_version: the version used when a line does not provide one
+    pub fn read_asset_identities(
+        reader: impl BufRead,
+        default_version: &str,
+    ) -> Result<Vec<AssetIdentity>, ApiError> {
+        let mut identities = vec![];
+
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with('{') || line.starts_with('[') {
+                let value: Value = serde_json::from_str(line)?;
+                let values = match value {
+                    Value::Array(values) => values,
+                    value => vec![value],
+                };
+                for value in &values {
+                    let value = value
+                        .get("identity")
+                        .or_else(|| value.get("asset"))
+                        .unwrap_or(value);
+                    let id = value.get("id").and_then(Value::as_str);
+                    let version = match value.get("version") {
+                        Some(Value::String(version)) => version.to_owned(),
+                        Some(Value::Number(version)) => version.to_string(),
+                        _ => default_version.to_owned(),
+                    };
+                    match id {
+                        Some(id) => identities.push(AssetIdentity::new(id.to_owned(), version)),
+                        None => return Err(ApiError::InvalidAssetIdentity(value.to_string())),
+                    }
+                }
+            } else {
+                let mut parts = line
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|p| !p.is_empty());
+                let id = parts.next().unwrap_or_default();
+                let version = parts.next().unwrap_or(default_version);
+                identities.push(AssetIdentity::new(id.to_owned(), version.to_owned()));
+            }
+        }
+
+        Ok(identities)
+    }
+
     /// Download the files associated with an existing asset.
     ///
     /// Parameters:
@@ -220,171 +1059,3126 @@ impl Api {
     }
 
     /// Parses an input file, extracts the names and values for all
-    /// properties specified in the file and upserts them to the
-    /// asset.
-    ///
-    /// The current implementation only works with a CSV file format.
-    /// The CSV must have only two columns with a header line containing the column names, which are: Name, Value
+    /// properties specified in the file and applies them to the
+    /// asset according to the mode.
     ///
-    /// Here is an example CSV file that could be used as an input to this function:
+    /// See read_metadata_file for the supported formats. Here is an example CSV file that could be used
+    /// as an input to this function:
     ///
     /// ````csv
     /// Name, Value
     /// My_property_name, My_property_value
     /// ````
+    ///
+    /// Returns the list of changed fields with their values before and after the upload.
+    ///
+    /// Parameters:
+    ///
+    /// * identity - the asset identity
+    /// * data_file_path - path to the metadata file
+    /// * format - the file format. If None, it is detected from the file extension
+    /// * separator - separator for the keys of nested values
+    /// * mode - how the uploaded values are combined with the existing metadata
+    /// * dry_run - if true, the changes are only computed, but not applied
     pub async fn upload_asset_metadata(
         &mut self,
         identity: &AssetIdentity,
-        data_file_path: &PathBuf,
-    ) -> Result<(), ApiError> {
+        data_file_path: &Path,
+        format: Option<MetadataFormat>,
+        separator: &str,
+        mode: MetadataMode,
+        dry_run: bool,
+    ) -> Result<Vec<MetadataChange>, ApiError> {
         self.init().await?;
-        log::trace!("Uploading asset metadata for asset {}...", identity.id());
+        log::trace!(
+            "Uploading asset metadata for asset {} in {} mode...",
+            identity.id(),
+            mode
+        );
 
         log::trace!("Reading data from file...");
-        let file = File::open(data_file_path)?;
-        let mut rdr = csv::Reader::from_reader(file);
-        let mut records: HashMap<String, Option<String>> = HashMap::new();
-        for result in rdr.deserialize() {
-            // Notice that we need to provide a type hint for automatic
-            // deserialization.
-            let record: MetadataEntry = result?;
-            records.insert(record.name, record.value);
-        }
+        let records: HashMap<String, Option<Value>> =
+            Self::read_metadata_file(data_file_path, format, separator)?
+                .into_iter()
+                .map(|(k, v)| (k, Some(v)))
+                .collect();
 
         match &self.client {
             Some(client) => {
-                let asset = client.get_asset(identity).await?;
-
-                match asset {
-                    Some(mut asset) => {
-                        // filter out any properties that may have no value
-                        let md: HashMap<String, String> = records
-                            .into_iter()
-                            .filter_map(|(k, v)| {
-                                if v.is_some() {
-                                    Some((k, v.unwrap()))
-                                } else {
-                                    None
-                                }
-                            })
-                            .collect();
-
-                        match &self.client {
-                            Some(client) => {
-                                // read the list of registered properties
-                                for (n, _) in md.iter() {
-                                    let definition = client.get_metadata_definition(&n).await;
-                                    match definition {
-                                        Ok(definition) => match definition {
-                                            Some(_) => (),
-                                            None => {
-                                                client.register_metadata_definition(&n).await?;
-                                            }
-                                        },
-                                        Err(e) => {
-                                            println!(
-                                                "Error while obtaining property definition for {}. Error: {}",
-                                                n.to_owned(), e
-                                            );
-                                        }
-                                    }
-                                }
-
-                                let metadata: Option<Option<HashMap<String, String>>> =
-                                    Some(Some(md));
-                                asset.set_metadata(metadata);
-                                Ok(client.update_asset(&asset).await?)
-                            }
-                            None => Err(ApiError::ClientNotInitialized),
-                        }
-                    }
-                    None => Err(ApiError::AssetNotFound),
+                let mut definitions = Self::metadata_definitions(
+                    client,
+                    records
+                        .iter()
+                        .filter(|(_, v)| {
+                            v.as_ref()
+                                .is_some_and(|v| !Self::is_empty_metadata_value(v))
+                        })
+                        .map(|(k, _)| k),
+                )
+                .await?;
+                if !dry_run {
+                    Self::register_metadata_definitions(client, &mut definitions, |n| {
+                        Self::default_field(records.get(n).and_then(|v| v.as_ref()))
+                    })
+                    .await?;
                 }
+
+                Self::apply_asset_metadata(client, &definitions, identity, records, mode, dry_run)
+                    .await
             }
             None => Err(ApiError::ClientNotInitialized),
         }
     }
 
-    pub async fn delete_asset_metadata(
-        &mut self,
+    /// Applies metadata values to an asset and returns the changed fields.
+    ///
+    /// The field definitions are expected to be registered already.
+    ///
+    /// Parameters:
+    ///
+    /// * client - the HTTP client
+    /// * definitions - the definitions as returned by metadata_definitions
+    /// * identity - the asset identity
+    /// * records - the values to apply. A value of None, null, an empty string or an empty array denotes an empty value
+    /// * mode - how the values are combined with the existing metadata
+    /// * dry_run - if true, the changes are only computed, but not applied
+    async fn apply_asset_metadata(
+        client: &Client,
+        definitions: &HashMap<String, Option<MetadataDefinition>>,
         identity: &AssetIdentity,
-        keys: &Vec<String>,
-    ) -> Result<(), ApiError> {
-        self.init().await?;
-        log::trace!("Deleting asset metadata for asset {}...", identity.id());
+        records: HashMap<String, Option<Value>>,
+        mode: MetadataMode,
+        dry_run: bool,
+    ) -> Result<Vec<MetadataChange>, ApiError> {
+        let mut values: HashMap<String, Option<Value>> = HashMap::new();
+        for (key, value) in records {
+            let value = match value.filter(|v| !Self::is_empty_metadata_value(v)) {
+                Some(value) => Some(Self::coerce_metadata_value(definitions, &key, value)?),
+                None => None,
+            };
+            values.insert(key, value);
+        }
 
-        match &self.client {
-            Some(client) => {
-                let asset = client.get_asset(identity).await?;
+        let mut asset = match client.get_asset(identity).await? {
+            Some(asset) => asset,
+            None => return Err(ApiError::AssetNotFound),
+        };
 
-                match asset {
-                    Some(asset) => {
-                        log::trace!("The asset exists");
+        let current = asset.metadata().flatten().unwrap_or_default();
+        let target = Self::combine_metadata(&current, values, mode);
+        let changes = Self::metadata_changes(&current, &target);
 
-                        client.delete_metadata(&asset.identity(), keys).await?;
+        if dry_run || changes.is_empty() {
+            return Ok(changes);
+        }
 
-                        Ok(())
-                    }
-                    None => Err(ApiError::AssetNotFound),
-                }
-            }
-            None => Err(ApiError::ClientNotInitialized),
+        let removed: Vec<String> = changes
+            .iter()
+            .filter(|c| c.after.is_none())
+            .map(|c| c.key.to_owned())
+            .collect();
+        if !removed.is_empty() {
+            client.delete_metadata(identity, &removed).await?;
         }
+
+        asset.set_metadata(Some(Some(target)));
+        client.update_asset(&asset).await?;
+
+        Ok(changes)
+    }
+
+    /// Applies the metadata from a manifest file to many assets and returns the result for each row.
+    ///
+    /// The manifest is a CSV or spreadsheet (xlsx, xls, ods) file with a header line. Each row identifies
+    /// an asset either by the AssetId and (optionally) AssetVersion columns or by the AssetName column.
+    /// All other columns are metadata fields. Rows are applied concurrently and a failure in one row
+    /// does not stop the others.
+    ///
+    /// Parameters:
+    ///
+    /// * path - path to the manifest file
+    /// * mode - how the values are combined with the existing metadata
+    /// * dry_run - if true, the changes are only computed, but not applied
+    pub async fn import_asset_metadata(
+        &mut self,
+        path: &Path,
+        mode: MetadataMode,
+        dry_run: bool,
+    ) -> Result<Vec<MetadataImportResult>, ApiError> {
+        self.init().await?;
+        log::trace!("Importing asset metadata from {}...", path.display());
+
+        let (header, rows) = Self::read_table(path)?;
+        let column = |name: &str| header.iter().position(|h| h.eq_ignore_ascii_case(name));
+        let id_column = column(METADATA_COLUMN_ASSET_ID);
+        let version_column = column(METADATA_COLUMN_ASSET_VERSION);
+        let name_column = column(METADATA_COLUMN_ASSET_NAME);
+        if id_column.is_none() && name_column.is_none() {
+            return Err(ApiError::InvalidManifest(format!(
+                "either {} or {} column is required",
+                METADATA_COLUMN_ASSET_ID, METADATA_COLUMN_ASSET_NAME
+            )));
+        }
+        let fields: Vec<(usize, &String)> = header
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| ![id_column, version_column, name_column].contains(&Some(*i)))
+            .collect();
+
+        let field_types: HashMap<&String, MetadataFieldType> = fields
+            .iter()
+            .map(|(c, f)| {
+                let values = rows
+                    .iter()
+                    .filter_map(|row| row.get(*c))
+                    .map(|v| v.trim())
+                    .filter(|v| !v.is_empty());
+                (*f, Self::infer_field_type(values))
+            })
+            .collect();
+
+        match &self.client {
+            Some(client) => {
+                let mut definitions =
+                    Self::metadata_definitions(client, fields.iter().map(|(_, f)| *f)).await?;
+                if !dry_run {
+                    Self::register_metadata_definitions(client, &mut definitions, |n| {
+                        let field_type = field_types.get(n).copied();
+                        (field_type.unwrap_or(MetadataFieldType::Text), None)
+                    })
+                    .await?;
+                }
+                let definitions = &definitions;
+
+                let results = stream::iter(rows.into_iter().enumerate())
+                    .map(|(i, row)| {
+                        let cell = move |c: Option<usize>| {
+                            c.and_then(|c| row.get(c))
+                                .map(|v| v.trim().to_owned())
+                                .filter(|v| !v.is_empty())
+                        };
+                        let id = cell(id_column);
+                        let version =
+                            cell(version_column).unwrap_or_else(|| LATEST_VERSION.to_string());
+                        let name = cell(name_column);
+                        let records: HashMap<String, Option<Value>> = fields
+                            .iter()
+                            .map(|(c, f)| (f.to_string(), cell(Some(*c)).map(Value::String)))
+                            .collect();
+
+                        async move {
+                            // row numbers start at 1 and include the header line
+                            let row = i + 2;
+                            let identity = match (id, name) {
+                                (Some(id), _) => Ok(AssetIdentity::new(id, version)),
+                                (None, Some(name)) => Self::find_asset_by_name(client, &name).await,
+                                (None, None) => Err(ApiError::InvalidManifest(format!(
+                                    "row {} does not identify an asset",
+                                    row
+                                ))),
+                            };
+                            let identity = match identity {
+                                Ok(identity) => identity,
+                                Err(e) => {
+                                    return MetadataImportResult {
+                                        row,
+                                        asset: None,
+                                        status: MetadataImportStatus::Failed,
+                                        changes: vec![],
+                                        error: Some(e.to_string()),
+                                    }
+                                }
+                            };
+
+                            let result = Self::apply_asset_metadata(
+                                client,
+                                definitions,
+                                &identity,
+                                records,
+                                mode,
+                                dry_run,
+                            )
+                            .await;
+
+                            match result {
+                                Ok(changes) => MetadataImportResult {
+                                    row,
+                                    asset: Some(identity),
+                                    status: if changes.is_empty() {
+                                        MetadataImportStatus::Unchanged
+                                    } else if dry_run {
+                                        MetadataImportStatus::Pending
+                                    } else {
+                                        MetadataImportStatus::Updated
+                                    },
+                                    changes,
+                                    error: None,
+                                },
+                                Err(e) => MetadataImportResult {
+                                    row,
+                                    asset: Some(identity),
+                                    status: MetadataImportStatus::Failed,
+                                    changes: vec![],
+                                    error: Some(e.to_string()),
+                                },
+                            }
+                        }
+                    })
+                    .buffered(DEFAULT_CONCURRENCY)
+                    .collect::<Vec<MetadataImportResult>>()
+                    .await;
+
+                Ok(results)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Returns the identity of the asset with exactly the given name.
+    ///
+    /// Parameters:
+    ///
+    /// * client - the HTTP client
+    /// * name - the asset name
+    async fn find_asset_by_name(client: &Client, name: &str) -> Result<AssetIdentity, ApiError> {
+        let assets: Vec<Asset> = client
+            .search_asset(None, Some(name.to_owned()))
+            .await?
+            .into_iter()
+            .filter(|a| a.name() == name)
+            .collect();
+
+        match assets.as_slice() {
+            [asset] => Ok(asset.identity()),
+            [] => Err(ApiError::AssetNotFound),
+            _ => Err(ApiError::InvalidManifest(format!(
+                "asset name {} is not unique",
+                name
+            ))),
+        }
+    }
+
+    /// Reads a table with a header line from a CSV or spreadsheet file.
+    /// Only the first sheet of a spreadsheet is read.
+    ///
+    /// Parameters:
+    ///
+    /// * path - path to the file
+    fn read_table(path: &Path) -> Result<(Vec<String>, Vec<Vec<String>>), ApiError> {
+        let extension = path
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        let mut rows: Vec<Vec<String>> = match extension.as_str() {
+            "csv" => {
+                let mut rdr = csv::ReaderBuilder::new()
+                    .has_headers(false)
+                    .flexible(true)
+                    .from_reader(File::open(path)?);
+                let mut rows = vec![];
+                for record in rdr.records() {
+                    rows.push(record?.iter().map(|v| v.to_owned()).collect());
+                }
+                rows
+            }
+            "xlsx" | "xlsm" | "xls" | "ods" => {
+                let mut workbook = calamine::open_workbook_auto(path)?;
+                let range = match workbook.worksheet_range_at(0) {
+                    Some(range) => range?,
+                    None => return Err(ApiError::InvalidManifest("no sheets found".to_string())),
+                };
+                range
+                    .rows()
+                    .map(|row| row.iter().map(Self::cell_to_string).collect())
+                    .collect()
+            }
+            _ => return Err(ApiError::UnsupportedFileFormat(extension)),
+        };
+
+        if rows.is_empty() {
+            return Err(ApiError::InvalidManifest("missing header line".to_string()));
+        }
+        let header = rows
+            .remove(0)
+            .into_iter()
+            .map(|h| h.trim().to_owned())
+            .collect();
+
+        Ok((header, rows))
+    }
+
+    /// Returns the text representation of a spreadsheet cell.
+    fn cell_to_string(cell: &calamine::Data) -> String {
+        use calamine::{Data, DataType};
+
+        match cell {
+            Data::Empty | Data::Error(_) => String::new(),
+            Data::Float(f) if f.fract() == 0.0 && f.abs() < 1e15 => format!("{}", *f as i64),
+            Data::DateTime(_) => match cell.as_datetime() {
+                Some(datetime) if datetime.time() == chrono::NaiveTime::MIN => {
+                    datetime.format("%Y-%m-%d").to_string()
+                }
+                Some(datetime) => datetime.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+                None => cell.to_string(),
+            },
+            _ => cell.to_string(),
+        }
+    }
+
+    /// Returns the metadata resulting from applying uploaded values to the current metadata.
+    ///
+    /// Parameters:
+    ///
+    /// * current - the current asset metadata
+    /// * values - the uploaded values. A value of None denotes an empty value
+    /// * mode - how the uploaded values are combined with the current metadata
+    pub fn combine_metadata(
+        current: &HashMap<String, Value>,
+        values: HashMap<String, Option<Value>>,
+        mode: MetadataMode,
+    ) -> HashMap<String, Value> {
+        let mut target = match mode {
+            MetadataMode::Replace => HashMap::new(),
+            MetadataMode::Merge | MetadataMode::Patch => current.clone(),
+        };
+
+        for (key, value) in values {
+            match value {
+                Some(value) => {
+                    target.insert(key, value);
+                }
+                None => {
+                    if mode == MetadataMode::Patch {
+                        target.remove(&key);
+                    }
+                }
+            }
+        }
+
+        target
+    }
+
+    /// Returns the fields that differ between two metadata states, ordered by key.
+    ///
+    /// Parameters:
+    ///
+    /// * before - the metadata before the change
+    /// * after - the metadata after the change
+    pub fn metadata_changes(
+        before: &HashMap<String, Value>,
+        after: &HashMap<String, Value>,
+    ) -> Vec<MetadataChange> {
+        let mut keys: Vec<&String> = before.keys().chain(after.keys()).collect();
+        keys.sort();
+        keys.dedup();
+
+        keys.into_iter()
+            .filter(|k| before.get(*k) != after.get(*k))
+            .map(|k| MetadataChange {
+                key: k.to_owned(),
+                before: before.get(k).cloned(),
+                after: after.get(k).cloned(),
+            })
+            .collect()
+    }
+
+    /// Looks up the metadata field definitions in the organization.
+    ///
+    /// Fields that do not exist yet are mapped to None.
+    ///
+    /// Parameters:
+    ///
+    /// * client - the HTTP client
+    /// * names - the metadata field names
+    async fn metadata_definitions<'a>(
+        client: &Client,
+        names: impl Iterator<Item = &'a String>,
+    ) -> Result<HashMap<String, Option<MetadataDefinition>>, ApiError> {
+        let mut definitions = HashMap::new();
+        for n in names {
+            let definition = client.get_metadata_definition(n).await?;
+            definitions.insert(n.to_owned(), definition);
+        }
+        Ok(definitions)
+    }
+
+    /// Registers the metadata field definitions that do not exist yet in the organization
+    /// and adds them to the definitions.
+    ///
+    /// Parameters:
+    ///
+    /// * client - the HTTP client
+    /// * definitions - the definitions as returned by metadata_definitions
+    /// * new_field - returns the type and accepted values of a new field
+    async fn register_metadata_definitions(
+        client: &Client,
+        definitions: &mut HashMap<String, Option<MetadataDefinition>>,
+        new_field: impl Fn(&String) -> (MetadataFieldType, Option<Vec<String>>),
+    ) -> Result<(), ApiError> {
+        for (n, definition) in definitions.iter_mut() {
+            if definition.is_none() {
+                let (field_type, accepted_values) = new_field(n);
+                let new_definition = MetadataDefinition::new(
+                    n.to_owned(),
+                    n.to_owned(),
+                    field_type,
+                    accepted_values,
+                );
+                client.register_metadata_definition(&new_definition).await?;
+                *definition = Some(new_definition);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the type and accepted values of a new field for a value: multi-select for lists,
+    /// accepting the distinct values in the list, otherwise text.
+    fn default_field(value: Option<&Value>) -> (MetadataFieldType, Option<Vec<String>>) {
+        match value {
+            Some(Value::Array(values)) => {
+                let mut accepted_values: Vec<String> = Vec::new();
+                for v in values {
+                    let v = match v {
+                        Value::String(s) => s.to_owned(),
+                        v => v.to_string(),
+                    };
+                    if !accepted_values.contains(&v) {
+                        accepted_values.push(v);
+                    }
+                }
+                let accepted_values = (!accepted_values.is_empty()).then_some(accepted_values);
+                (MetadataFieldType::MultiSelect, accepted_values)
+            }
+            _ => (MetadataFieldType::Text, None),
+        }
+    }
+
+    /// Returns the type of a new field for the values of a manifest column: number, boolean or
+    /// timestamp if all values can be converted to that type, otherwise text.
+    fn infer_field_type<'a>(values: impl Iterator<Item = &'a str> + Clone) -> MetadataFieldType {
+        [
+            MetadataFieldType::Number,
+            MetadataFieldType::Boolean,
+            MetadataFieldType::Timestamp,
+        ]
+        .into_iter()
+        .find(|field_type| {
+            let definition =
+                MetadataDefinition::new(String::new(), String::new(), *field_type, None);
+            let mut values = values.clone().peekable();
+            values.peek().is_some()
+                && values.all(|v| definition.coerce(&Value::String(v.to_owned())).is_ok())
+        })
+        .unwrap_or(MetadataFieldType::Text)
+    }
+
+    /// Validates and converts metadata values according to the field definitions.
+    ///
+    /// Parameters:
+    ///
+    /// * definitions - the definitions as returned by metadata_definitions
+    /// * values - the metadata values
+    fn coerce_metadata(
+        definitions: &HashMap<String, Option<MetadataDefinition>>,
+        values: HashMap<String, Value>,
+    ) -> Result<HashMap<String, Value>, ApiError> {
+        values
+            .into_iter()
+            .map(|(k, v)| {
+                let v = Self::coerce_metadata_value(definitions, &k, v)?;
+                Ok((k, v))
+            })
+            .collect()
+    }
+
+    /// Validates and converts a single metadata value. Fields without a definition
+    /// are treated as text (or multi-select for lists), since that is how they are going to be registered.
+    fn coerce_metadata_value(
+        definitions: &HashMap<String, Option<MetadataDefinition>>,
+        name: &str,
+        value: Value,
+    ) -> Result<Value, ApiError> {
+        match definitions.get(name) {
+            Some(Some(definition)) => Ok(definition.coerce(&value)?),
+            _ => Ok(match value {
+                Value::String(_) => value,
+                Value::Array(values) => Value::Array(
+                    values
+                        .into_iter()
+                        .map(|v| match v {
+                            Value::String(_) => v,
+                            v => Value::String(v.to_string()),
+                        })
+                        .collect(),
+                ),
+                value => Value::String(value.to_string()),
+            }),
+        }
+    }
+
+    /// Returns true for values that denote an empty field: null, an empty string or an empty list.
+    pub fn is_empty_metadata_value(value: &Value) -> bool {
+        match value {
+            Value::Null => true,
+            Value::String(s) => s.is_empty(),
+            Value::Array(values) => values.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Reads metadata field values from a file.
+    ///
+    /// The supported formats are:
+    ///
+    /// * csv - two columns with a header line: Name, Value
+    /// * json, yaml - an object with field names as keys
+    ///
+    /// Nested objects are flattened by joining the keys with the separator, e.g. {"dimensions": {"width": 10}}
+    /// becomes the field dimensions.width. Lists are kept as they are and map to multi-select fields.
+    /// Empty values are returned as null.
+    ///
+    /// Parameters:
+    ///
+    /// * path - path to the metadata file
+    /// * format - the file format. If None, it is detected from the file extension
+    /// * separator - separator for the keys of nested values
+    pub fn read_metadata_file(
+        path: &Path,
+        format: Option<MetadataFormat>,
+        separator: &str,
+    ) -> Result<HashMap<String, Value>, ApiError> {
+        let value = Self::parse_metadata_file(path, format)?;
+        Self::flatten_metadata_object(path, value, separator)
+    }
+
+    /// Returns the path of the sidecar file for a data file if one exists.
+    ///
+    /// Parameters:
+    ///
+    /// * path - path to the data file
+    pub fn sidecar_path(path: &Path) -> Option<PathBuf> {
+        SIDECAR_EXTENSIONS
+            .iter()
+            .map(|extension| {
+                let mut sidecar = path.as_os_str().to_owned();
+                sidecar.push(format!("{}.{}", SIDECAR_SUFFIX, extension));
+                PathBuf::from(sidecar)
+            })
+            .find(|sidecar| sidecar.is_file())
+    }
+
+    /// Returns the value found in one of several sidecar files, or an error if two of them differ.
+    fn combine_sidecar_value<T: PartialEq>(
+        key: &str,
+        existing: Option<T>,
+        value: Option<T>,
+    ) -> Result<Option<T>, ApiError> {
+        match (existing, value) {
+            (Some(existing), Some(value)) if existing != value => {
+                Err(ApiError::SidecarConflict(key.to_owned()))
+            }
+            (existing, value) => Ok(existing.or(value)),
+        }
+    }
+
+    /// Reads a sidecar metadata file.
+    ///
+    /// A CSV sidecar file contains only metadata in the same format as for read_metadata_file.
+    /// A JSON or YAML sidecar file is either an object with the metadata fields or an object with
+    /// the optional keys name, description, tags and metadata. The latter is only assumed if the object
+    /// has no other keys and metadata is an object, so that a plain field named metadata still works:
+    ///
+    /// ````yaml
+    /// name: Bracket
+    /// description: Mounting bracket
+    /// tags: [mechanical, steel]
+    /// metadata:
+    ///   Material: Steel
+    /// ````
+    ///
+    /// Parameters:
+    ///
+    /// * path - path to the sidecar file
+    pub fn read_sidecar(path: &Path) -> Result<SidecarMetadata, ApiError> {
+        let value = Self::parse_metadata_file(path, None)?;
+
+        let wrapped = match &value {
+            Value::Object(map) => {
+                matches!(
+                    map.get("metadata"),
+                    Some(Value::Object(_)) | Some(Value::Null)
+                ) && map
+                    .keys()
+                    .all(|k| ["name", "description", "tags", "metadata"].contains(&k.as_str()))
+            }
+            _ => false,
+        };
+
+        match value {
+            Value::Object(mut map) if wrapped => {
+                let text = |value: Option<Value>| match value {
+                    Some(Value::String(s)) if !s.is_empty() => Some(s),
+                    _ => None,
+                };
+                let name = text(map.remove("name"));
+                let description = text(map.remove("description"));
+                let tags = match map.remove("tags") {
+                    Some(Value::Array(tags)) => {
+                        tags.into_iter().filter_map(|t| text(Some(t))).collect()
+                    }
+                    Some(Value::String(tags)) => tags
+                        .split(',')
+                        .map(|t| t.trim().to_owned())
+                        .filter(|t| !t.is_empty())
+                        .collect(),
+                    _ => vec![],
+                };
+                let metadata = match map.remove("metadata") {
+                    Some(Value::Null) | None => HashMap::new(),
+                    Some(metadata) => Self::flatten_metadata_object(
+                        path,
+                        metadata,
+                        DEFAULT_METADATA_KEY_SEPARATOR,
+                    )?,
+                };
+
+                Ok(SidecarMetadata {
+                    name,
+                    description,
+                    tags,
+                    metadata,
+                })
+            }
+            value => Ok(SidecarMetadata {
+                metadata: Self::flatten_metadata_object(
+                    path,
+                    value,
+                    DEFAULT_METADATA_KEY_SEPARATOR,
+                )?,
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Parses a metadata file into a JSON value.
+    ///
+    /// Parameters:
+    ///
+    /// * path - path to the metadata file
+    /// * format - the file format. If None, it is detected from the file extension
+    fn parse_metadata_file(path: &Path, format: Option<MetadataFormat>) -> Result<Value, ApiError> {
+        let format = match format.or_else(|| MetadataFormat::from_path(path)) {
+            Some(format) => format,
+            None => {
+                let extension = path
+                    .extension()
+                    .map(|e| e.to_string_lossy().to_lowercase())
+                    .unwrap_or_default();
+                return Err(ApiError::UnsupportedFileFormat(extension));
+            }
+        };
+
+        let value: Value = match format {
+            MetadataFormat::Csv => {
+                let mut rdr = csv::Reader::from_reader(File::open(path)?);
+                let mut values = serde_json::Map::new();
+                for result in rdr.deserialize() {
+                    // Notice that we need to provide a type hint for automatic
+                    // deserialization.
+                    let record: MetadataEntry = result?;
+                    values.insert(
+                        record.name,
+                        record.value.map(Value::String).unwrap_or(Value::Null),
+                    );
+                }
+                Value::Object(values)
+            }
+            MetadataFormat::Json => serde_json::from_reader(File::open(path)?)?,
+            MetadataFormat::Yaml => serde_yaml::from_reader(File::open(path)?)?,
+        };
+
+        Ok(value)
+    }
+
+    /// Flattens the metadata object read from a file.
+    ///
+    /// Parameters:
+    ///
+    /// * path - path to the metadata file. Only used for error reporting
+    /// * value - the metadata object
+    /// * separator - separator for the keys of nested values
+    fn flatten_metadata_object(
+        path: &Path,
+        value: Value,
+        separator: &str,
+    ) -> Result<HashMap<String, Value>, ApiError> {
+        match value {
+            Value::Object(_) => {
+                let mut values = HashMap::new();
+                Self::flatten_metadata(None, value, separator, &mut values);
+                Ok(values)
+            }
+            _ => Err(ApiError::InvalidMetadataFile(format!(
+                "expected an object with field names as keys in {}",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Flattens nested objects into fields with keys joined by the separator.
+    fn flatten_metadata(
+        prefix: Option<String>,
+        value: Value,
+        separator: &str,
+        values: &mut HashMap<String, Value>,
+    ) {
+        match (prefix, value) {
+            (prefix, Value::Object(map)) => {
+                for (key, value) in map {
+                    let key = match &prefix {
+                        Some(prefix) => format!("{}{}{}", prefix, separator, key),
+                        None => key,
+                    };
+                    Self::flatten_metadata(Some(key), value, separator, values);
+                }
+            }
+            (Some(key), value) => {
+                values.insert(key, value);
+            }
+            (None, _) => (),
+        }
+    }
+
+    pub async fn delete_asset_metadata(
+        &mut self,
+        identity: &AssetIdentity,
+        keys: &[String],
+    ) -> Result<(), ApiError> {
+        self.init().await?;
+        log::trace!("Deleting asset metadata for asset {}...", identity.id());
+
+        match &self.client {
+            Some(client) => {
+                let asset = client.get_asset(identity).await?;
+
+                match asset {
+                    Some(asset) => {
+                        log::trace!("The asset exists");
+
+                        client.delete_metadata(&asset.identity(), keys).await?;
+
+                        Ok(())
+                    }
+                    None => Err(ApiError::AssetNotFound),
+                }
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Returns the metadata of an asset ordered by field name.
+    ///
+    /// Parameters:
+    ///
+    /// * identity - the asset identity
+    pub async fn get_asset_metadata(
+        &mut self,
+        identity: &AssetIdentity,
+    ) -> Result<BTreeMap<String, Value>, ApiError> {
+        self.init().await?;
+        log::trace!("Getting asset metadata for asset {}...", identity.id());
+
+        match &self.client {
+            Some(client) => match client.get_asset(identity).await? {
+                Some(asset) => Ok(asset
+                    .metadata()
+                    .flatten()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect()),
+                None => Err(ApiError::AssetNotFound),
+            },
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Writes the metadata of the assets matching the search criteria to a CSV file and
+    /// returns the number of exported assets.
+    ///
+    /// The file has one row per asset and one column per metadata field. The first columns identify
+    /// the asset: AssetId, AssetVersion, AssetName. Fields that are not set on an asset are left empty.
+    /// Multi-select values are written as a comma-separated list.
+    ///
+    /// Parameters:
+    ///
+    /// * asset_id - optional asset identity to export a single asset
+    /// * asset_name - optional asset name filter
+    /// * output - path to the output CSV file
+    pub async fn export_asset_metadata(
+        &mut self,
+        asset_id: Option<AssetIdentity>,
+        asset_name: Option<String>,
+        output: &Path,
+    ) -> Result<usize, ApiError> {
+        let assets = self.search_asset(asset_id, asset_name).await?;
+        log::trace!("Exporting metadata for {} assets...", assets.len());
+
+        let rows: Vec<(Asset, HashMap<String, Value>)> = assets
+            .into_iter()
+            .map(|a| {
+                let metadata = a.metadata().flatten().unwrap_or_default();
+                (a, metadata)
+            })
+            .collect();
+        let fields: BTreeSet<&String> = rows.iter().flat_map(|(_, m)| m.keys()).collect();
+
+        let mut writer = csv::Writer::from_path(output)?;
+        let mut header = vec![
+            METADATA_COLUMN_ASSET_ID.to_string(),
+            METADATA_COLUMN_ASSET_VERSION.to_string(),
+            METADATA_COLUMN_ASSET_NAME.to_string(),
+        ];
+        header.extend(fields.iter().map(|f| f.to_string()));
+        writer.write_record(&header)?;
+
+        for (asset, metadata) in &rows {
+            let mut record = vec![
+                asset.identity().id(),
+                asset.identity().version(),
+                asset.name(),
+            ];
+            record.extend(fields.iter().map(|f| match metadata.get(*f) {
+                Some(value) => Self::metadata_value_to_string(value),
+                None => String::new(),
+            }));
+            writer.write_record(&record)?;
+        }
+        writer.flush()?;
+
+        Ok(rows.len())
+    }
+
+    /// Returns the text representation of a metadata value as used in CSV files.
+    fn metadata_value_to_string(value: &Value) -> String {
+        match value {
+            Value::Null => String::new(),
+            Value::String(s) => s.to_owned(),
+            Value::Array(values) => values
+                .iter()
+                .map(Self::metadata_value_to_string)
+                .collect::<Vec<String>>()
+                .join(","),
+            value => value.to_string(),
+        }
+    }
+
+    /// Returns all metadata field definitions in the organization.
+    pub async fn list_metadata_definitions(&mut self) -> Result<Vec<MetadataDefinition>, ApiError> {
+        self.init().await?;
+        log::trace!("Listing metadata field definitions...");
+
+        match &self.client {
+            Some(client) => Ok(client.list_metadata_definitions().await?),
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Returns the definition of a metadata field.
+    ///
+    /// Parameters:
+    ///
+    /// * name - the field name
+    pub async fn get_metadata_definition(
+        &mut self,
+        name: &String,
+    ) -> Result<MetadataDefinition, ApiError> {
+        self.init().await?;
+        log::trace!("Getting metadata field definition {}...", name);
+
+        match &self.client {
+            Some(client) => match client.get_metadata_definition(name).await? {
+                Some(definition) => Ok(definition),
+                None => Err(ApiError::MetadataFieldNotFound(name.to_owned())),
+            },
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Creates a new metadata field definition in the organization.
+    ///
+    /// Parameters:
+    ///
+    /// * definition - the new field definition
+    pub async fn create_metadata_definition(
+        &mut self,
+        definition: &MetadataDefinition,
+    ) -> Result<(), ApiError> {
+        self.init().await?;
+        log::trace!("Creating metadata field definition {}...", definition.name);
+
+        match &self.client {
+            Some(client) => Ok(client.register_metadata_definition(definition).await?),
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Updates the display name and/or the accepted values of a metadata field and
+    /// returns the updated definition.
+    ///
+    /// Parameters:
+    ///
+    /// * name - the field name
+    /// * changes - the properties to be updated
+    pub async fn update_metadata_definition(
+        &mut self,
+        name: &String,
+        changes: &MetadataDefinitionUpdate,
+    ) -> Result<MetadataDefinition, ApiError> {
+        self.init().await?;
+        log::trace!("Updating metadata field definition {}...", name);
+
+        match &self.client {
+            Some(client) => {
+                if !changes.is_empty() {
+                    match client.update_metadata_definition(name, changes).await {
+                        Err(ClientError::NotFound) => {
+                            return Err(ApiError::MetadataFieldNotFound(name.to_owned()))
+                        }
+                        result => result?,
+                    }
+                }
+
+                match client.get_metadata_definition(name).await? {
+                    Some(definition) => Ok(definition),
+                    None => Err(ApiError::MetadataFieldNotFound(name.to_owned())),
+                }
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Deletes a metadata field definition from the organization.
+    ///
+    /// Parameters:
+    ///
+    /// * name - the field name
+    pub async fn delete_metadata_definition(&mut self, name: &String) -> Result<(), ApiError> {
+        self.init().await?;
+        log::trace!("Deleting metadata field definition {}...", name);
+
+        match &self.client {
+            Some(client) => match client.delete_metadata_definition(name).await {
+                Err(ClientError::NotFound) => Err(ApiError::MetadataFieldNotFound(name.to_owned())),
+                result => Ok(result?),
+            },
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Creates or updates the metadata field definitions in the organization to match a schema file
+    /// and returns the drift found for each field.
+    ///
+    /// Fields with a different type are reported as conflicts and left as is, because Unity does not
+    /// allow changing the type of an existing field. Fields that exist in the organization, but not in
+    /// the schema are reported as unmanaged and are never deleted.
+    ///
+    /// Parameters:
+    ///
+    /// * path - path to the schema file in YAML or JSON format
+    /// * dry_run - if true, only the drift is reported, but nothing is changed
+    pub async fn apply_metadata_schema(
+        &mut self,
+        path: &Path,
+        dry_run: bool,
+    ) -> Result<Vec<MetadataSchemaDrift>, ApiError> {
+        self.init().await?;
+        log::trace!("Applying metadata schema {}...", path.display());
+
+        let schema: MetadataSchema = serde_yaml::from_reader(File::open(path)?)?;
+
+        match &self.client {
+            Some(client) => {
+                let existing: HashMap<String, MetadataDefinition> = client
+                    .list_metadata_definitions()
+                    .await?
+                    .into_iter()
+                    .map(|d| (d.name.to_owned(), d))
+                    .collect();
+
+                let mut drift: Vec<MetadataSchemaDrift> = schema
+                    .fields
+                    .iter()
+                    .map(|f| f.drift(existing.get(&f.name)))
+                    .collect();
+                let mut unmanaged: Vec<MetadataSchemaDrift> = existing
+                    .keys()
+                    .filter(|name| !schema.fields.iter().any(|f| &f.name == *name))
+                    .map(|name| MetadataSchemaDrift {
+                        name: name.to_owned(),
+                        action: MetadataSchemaAction::Unmanaged,
+                        differences: vec![],
+                        changes: None,
+                    })
+                    .collect();
+                unmanaged.sort_by(|a, b| a.name.cmp(&b.name));
+
+                if !dry_run {
+                    for (field, drift) in schema.fields.iter().zip(drift.iter()) {
+                        match drift.action {
+                            MetadataSchemaAction::Create => {
+                                client
+                                    .register_metadata_definition(&field.definition())
+                                    .await?;
+                            }
+                            MetadataSchemaAction::Update => {
+                                if let Some(changes) = &drift.changes {
+                                    client
+                                        .update_metadata_definition(&field.name, changes)
+                                        .await?;
+                                }
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+
+                drift.append(&mut unmanaged);
+                Ok(drift)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Compares local files against the sizes and checksums stored in Unity for an asset.
+    ///
+    /// Local files are matched to the asset files by their path. If a directory is provided,
+    /// all files under it are compared using paths relative to the directory and any asset files
+    /// with no local counterpart are reported as missing.
+    ///
+    /// Parameters:
+    ///
+    /// * identity: a reference to the asset identity
+    /// * paths: local files and/or directories to verify
+    pub async fn verify_asset(
+        &mut self,
+        identity: &AssetIdentity,
+        paths: Vec<&PathBuf>,
+    ) -> Result<Vec<FileVerification>, ApiError> {
+        self.init().await?;
+        log::trace!("Verifying files for asset {}...", identity.id());
+        let default_algorithm = self.configuration.borrow().checksum_algorithm();
+
+        match &self.client {
+            Some(client) => {
+                let asset = client
+                    .get_asset(identity)
+                    .await?
+                    .ok_or(ApiError::AssetNotFound)?;
+                let remote_files = Self::asset_files(client, &asset).await?;
+
+                let mut local_files: Vec<(String, PathBuf)> = Vec::new();
+                let mut complete = false;
+                for path in paths {
+                    if path.is_dir() {
+                        complete = true;
+                        Self::collect_local_files(path, path, &mut local_files)?;
+                    } else {
+                        let name = path.file_name().unwrap_or_default().to_string_lossy();
+                        local_files.push((name.to_string(), path.to_owned()));
+                    }
+                }
+
+                let mut results: Vec<FileVerification> = Vec::new();
+                for (name, path) in local_files.iter() {
+                    let local_size = fs::metadata(path)?.len();
+                    let remote = remote_files.iter().find(|f| f.path().eq(name));
+                    let result = match remote {
+                        Some(remote) => {
+                            let remote_checksum = remote.user_checksum();
+                            let algorithm = remote_checksum
+                                .as_deref()
+                                .and_then(ChecksumAlgorithm::detect)
+                                .unwrap_or(default_algorithm);
+                            let local_checksum = algorithm.compute(path)?;
+                            let status = if remote.size() != Some(local_size) {
+                                VerificationStatus::SizeMismatch
+                            } else {
+                                match &remote_checksum {
+                                    Some(c) if c.eq_ignore_ascii_case(&local_checksum) => {
+                                        VerificationStatus::Match
+                                    }
+                                    Some(_) => VerificationStatus::ChecksumMismatch,
+                                    None => VerificationStatus::NoChecksum,
+                                }
+                            };
+                            FileVerification {
+                                path: name.to_owned(),
+                                local_size: Some(local_size),
+                                remote_size: remote.size(),
+                                local_checksum: Some(local_checksum),
+                                remote_checksum,
+                                status,
+                            }
+                        }
+                        None => FileVerification {
+                            path: name.to_owned(),
+                            local_size: Some(local_size),
+                            remote_size: None,
+                            local_checksum: None,
+                            remote_checksum: None,
+                            status: VerificationStatus::MissingRemote,
+                        },
+                    };
+                    results.push(result);
+                }
+
+                if complete {
+                    for remote in remote_files.iter() {
+                        if !local_files.iter().any(|(name, _)| remote.path().eq(name)) {
+                            results.push(FileVerification {
+                                path: remote.path(),
+                                local_size: None,
+                                remote_size: remote.size(),
+                                local_checksum: None,
+                                remote_checksum: remote.user_checksum(),
+                                status: VerificationStatus::MissingLocal,
+                            });
+                        }
+                    }
+                }
+
+                Ok(results)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Compares two versions of an asset.
+    ///
+    /// The name, description, tags, status, primary type and metadata are compared field by field.
+    /// Datasets are matched by name and their files by path. A file is modified if its size or
+    /// its checksum differ.
+    ///
+    /// Parameters:
+    ///
+    /// * asset_id - the asset ID
+    /// * from - the older version (e.g. 1)
+    /// * to - the newer version (e.g. latest)
+    pub async fn diff_asset_versions(
+        &mut self,
+        asset_id: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<AssetDiff, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let before = client
+                    .get_asset(&AssetIdentity::new(asset_id.to_owned(), from.to_owned()))
+                    .await?
+                    .ok_or(ApiError::AssetNotFound)?;
+                let after = client
+                    .get_asset(&AssetIdentity::new(asset_id.to_owned(), to.to_owned()))
+                    .await?
+                    .ok_or(ApiError::AssetNotFound)?;
+
+                let fields = Self::metadata_changes(
+                    &Self::asset_fields(&before),
+                    &Self::asset_fields(&after),
+                );
+                let metadata = Self::metadata_changes(
+                    &before.metadata().flatten().unwrap_or_default(),
+                    &after.metadata().flatten().unwrap_or_default(),
+                );
+
+                let files_before = Self::dataset_files(client, &before).await?;
+                let files_after = Self::dataset_files(client, &after).await?;
+                let mut diff = AssetDiff {
+                    asset_id: asset_id.to_owned(),
+                    from: before.identity().version(),
+                    to: after.identity().version(),
+                    fields,
+                    metadata,
+                    datasets_added: vec![],
+                    datasets_removed: vec![],
+                    files: vec![],
+                };
+                for (dataset, files) in files_before.iter() {
+                    match files_after.iter().find(|(d, _)| d == dataset) {
+                        Some((_, after)) => {
+                            diff.files.extend(Self::file_changes(dataset, files, after))
+                        }
+                        None => {
+                            diff.datasets_removed.push(dataset.to_owned());
+                            diff.files.extend(Self::file_changes(dataset, files, &[]));
+                        }
+                    }
+                }
+                for (dataset, files) in files_after.iter() {
+                    if !files_before.iter().any(|(d, _)| d == dataset) {
+                        diff.datasets_added.push(dataset.to_owned());
+                        diff.files.extend(Self::file_changes(dataset, &[], files));
+                    }
+                }
+
+                Ok(diff)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Compares the files in a local directory with the files of an asset.
+    ///
+    /// Local files are matched to the asset files by their path relative to the directory. Local files
+    /// that are not in the asset are added, asset files with no local counterpart are removed and files whose
+    /// size or checksum differ are modified. Local checksums use the algorithm of the matching asset file.
+    ///
+    /// Parameters:
+    ///
+    /// * identity - the asset identity (e.g. version latest)
+    /// * directory - the local directory
+    /// * dataset - (optional) only the files of this dataset (name or ID) are compared. If None, all datasets are
+    ///   compared and new files are reported in the Source dataset
+    pub async fn diff_local_files(
+        &mut self,
+        identity: &AssetIdentity,
+        directory: &Path,
+        dataset: Option<&str>,
+    ) -> Result<AssetDiff, ApiError> {
+        self.init().await?;
+        let default_algorithm = self.configuration.borrow().checksum_algorithm();
+
+        match &self.client {
+            Some(client) => {
+                let (_, diff) =
+                    Self::local_diff(client, identity, directory, dataset, default_algorithm)
+                        .await?;
+                Ok(diff)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Uploads the differences between a local directory and an asset version into a new version.
+    ///
+    /// The new version is created from the compared version with all its files. New and modified local files
+    /// are uploaded into it. Files missing locally are reported, but kept. The new version is not frozen.
+    ///
+    /// Parameters:
+    ///
+    /// * identity - the asset identity (e.g. version latest)
+    /// * directory - the local directory
+    /// * dataset - (optional) only the files of this dataset (name or ID) are compared. See diff_local_files
+    pub async fn apply_local_diff(
+        &mut self,
+        identity: &AssetIdentity,
+        directory: &Path,
+        dataset: Option<&str>,
+    ) -> Result<LocalDiffApplyResult, ApiError> {
+        self.init().await?;
+        let checksum_algorithm = self.configuration.borrow().checksum_algorithm();
+
+        match &self.client {
+            Some(client) => {
+                let (asset, diff) =
+                    Self::local_diff(client, identity, directory, dataset, checksum_algorithm)
+                        .await?;
+                let changes: Vec<&FileChange> = diff
+                    .files
+                    .iter()
+                    .filter(|f| f.status != FileChangeStatus::Removed)
+                    .collect();
+                if changes.is_empty() {
+                    log::trace!("No new or modified files to upload");
+                    return Ok(LocalDiffApplyResult {
+                        diff,
+                        version: None,
+                        uploads: vec![],
+                    });
+                }
+
+                let version = client.create_asset_version(&asset.identity()).await?;
+                let new_identity = AssetIdentity::new(asset.identity().id(), version);
+                log::trace!("Created version {}", new_identity.version());
+                let new_asset = client
+                    .get_asset(&new_identity)
+                    .await?
+                    .ok_or(ApiError::AssetNotFound)?;
+
+                let mut uploads: Vec<FileUploadResult> = Vec::new();
+                for change in changes {
+                    let dataset_id = Self::dataset_id(&new_asset, &change.dataset)?;
+                    let path = directory.join(&change.path);
+                    // files are uploaded with their relative path, so that nested files keep their place
+                    let status = match change.status {
+                        FileChangeStatus::Modified => {
+                            Self::replace_file(
+                                client,
+                                &new_identity,
+                                &dataset_id,
+                                &path,
+                                &change.path,
+                                &checksum_algorithm,
+                            )
+                            .await?;
+                            UploadStatus::Replaced
+                        }
+                        _ => {
+                            client
+                                .upload_file_as(
+                                    &new_identity,
+                                    &dataset_id,
+                                    &path,
+                                    &change.path,
+                                    &checksum_algorithm,
+                                )
+                                .await?;
+                            UploadStatus::Uploaded
+                        }
+                    };
+                    uploads.push(FileUploadResult {
+                        path: change.path.to_owned(),
+                        status,
+                    });
+                }
+
+                Ok(LocalDiffApplyResult {
+                    diff,
+                    version: client.get_asset_version(&new_identity).await?,
+                    uploads,
+                })
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Returns the asset and the differences between its files and a local directory. See diff_local_files.
+    async fn local_diff(
+        client: &Client,
+        identity: &AssetIdentity,
+        directory: &Path,
+        dataset: Option<&str>,
+        default_algorithm: ChecksumAlgorithm,
+    ) -> Result<(Asset, AssetDiff), ApiError> {
+        let asset = client
+            .get_asset(identity)
+            .await?
+            .ok_or(ApiError::AssetNotFound)?;
+        let mut datasets = Self::dataset_files(client, &asset).await?;
+        let target = match dataset {
+            Some(dataset) => {
+                let dataset_id = Self::dataset_id(&asset, dataset)?;
+                let name = asset
+                    .datasets()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find(|d| d.id() == dataset_id)
+                    .map(|d| d.name())
+                    .unwrap_or(dataset.to_owned());
+                datasets.retain(|(d, _)| *d == name);
+                name
+            }
+            None => SOURCE_DATASET_NAME.to_string(),
+        };
+
+        let mut local_paths: Vec<(String, PathBuf)> = Vec::new();
+        Self::collect_local_files(directory, directory, &mut local_paths)?;
+        local_paths.sort();
+
+        let mut local_files: Vec<AssetFile> = Vec::new();
+        for (name, path) in local_paths.iter() {
+            let algorithm = datasets
+                .iter()
+                .flat_map(|(_, files)| files.iter())
+                .find(|f| f.path() == *name)
+                .and_then(|f| f.user_checksum())
+                .and_then(|c| ChecksumAlgorithm::detect(&c))
+                .unwrap_or(default_algorithm);
+            local_files.push(AssetFile::new(
+                name.to_owned(),
+                Some(fs::metadata(path)?.len()),
+                Some(algorithm.compute(path)?),
+                None,
+            ));
+        }
+
+        let mut diff = AssetDiff {
+            asset_id: asset.identity().id(),
+            from: asset.identity().version(),
+            to: directory.to_string_lossy().to_string(),
+            fields: vec![],
+            metadata: vec![],
+            datasets_added: vec![],
+            datasets_removed: vec![],
+            files: vec![],
+        };
+        for (name, files) in datasets.iter() {
+            let local: Vec<AssetFile> = local_files
+                .iter()
+                .filter(|l| files.iter().any(|f| f.path() == l.path()))
+                .cloned()
+                .collect();
+            diff.files.extend(Self::file_changes(name, files, &local));
+        }
+        let new_files: Vec<AssetFile> = local_files
+            .into_iter()
+            .filter(|l| {
+                !datasets
+                    .iter()
+                    .any(|(_, files)| files.iter().any(|f| f.path() == l.path()))
+            })
+            .collect();
+        diff.files
+            .extend(Self::file_changes(&target, &[], &new_files));
+
+        Ok((asset, diff))
+    }
+
+    /// Returns the compared fields of an asset other than the metadata, by name.
+    fn asset_fields(asset: &Asset) -> HashMap<String, Value> {
+        let mut fields: HashMap<String, Value> = HashMap::new();
+        fields.insert("name".to_string(), Value::String(asset.name()));
+        if let Some(description) = asset.description() {
+            fields.insert("description".to_string(), Value::String(description));
+        }
+        let mut tags = asset.tags().unwrap_or_default();
+        tags.sort();
+        fields.insert("tags".to_string(), Value::from(tags));
+        fields.insert(
+            "status".to_string(),
+            serde_json::to_value(asset.status()).unwrap_or_default(),
+        );
+        fields.insert(
+            "primaryType".to_string(),
+            serde_json::to_value(asset.primary_type()).unwrap_or_default(),
+        );
+        fields
+    }
+
+    /// Returns the files of each dataset of an asset, by dataset name.
+    async fn dataset_files(
+        client: &Client,
+        asset: &Asset,
+    ) -> Result<Vec<(String, Vec<AssetFile>)>, ApiError> {
+        let mut datasets = vec![];
+        for dataset in asset.datasets().unwrap_or_default() {
+            let files = client.list_files(&asset.identity(), &dataset.id()).await?;
+            datasets.push((dataset.name(), files));
+        }
+        Ok(datasets)
+    }
+
+    /// Returns the differences between two states of the files of a dataset, ordered by path.
+    ///
+    /// Parameters:
+    ///
+    /// * dataset - the dataset name
+    /// * before - the files in the older state
+    /// * after - the files in the newer state
+    pub fn file_changes(
+        dataset: &str,
+        before: &[AssetFile],
+        after: &[AssetFile],
+    ) -> Vec<FileChange> {
+        let mut paths: Vec<String> = before.iter().chain(after).map(|f| f.path()).collect();
+        paths.sort();
+        paths.dedup();
+
+        paths
+            .into_iter()
+            .filter_map(|path| {
+                let old = before.iter().find(|f| f.path() == path);
+                let new = after.iter().find(|f| f.path() == path);
+                let status = match (old, new) {
+                    (Some(old), Some(new)) => {
+                        let checksums_differ = match (old.user_checksum(), new.user_checksum()) {
+                            (Some(a), Some(b)) => !a.eq_ignore_ascii_case(&b),
+                            _ => false,
+                        };
+                        if old.size() != new.size() || checksums_differ {
+                            FileChangeStatus::Modified
+                        } else {
+                            return None;
+                        }
+                    }
+                    (None, Some(_)) => FileChangeStatus::Added,
+                    (Some(_), None) => FileChangeStatus::Removed,
+                    (None, None) => return None,
+                };
+                Some(FileChange {
+                    dataset: dataset.to_owned(),
+                    path,
+                    status,
+                    size_before: old.and_then(|f| f.size()),
+                    size_after: new.and_then(|f| f.size()),
+                    checksum_before: old.and_then(|f| f.user_checksum()),
+                    checksum_after: new.and_then(|f| f.user_checksum()),
+                })
+            })
+            .collect()
+    }
+
+    /// Uploads files into a dataset of an existing asset.
+    ///
+    /// If a file with the same path already exists in the dataset, it is replaced (see replace_file).
+    /// When skip_unchanged is true, files whose size and checksum match the existing
+    /// file record are not uploaded again. Files are uploaded under their file name, so two
+    /// local files with the same name are rejected before anything is uploaded.
+    ///
+    /// Parameters:
+    ///
+    /// * identity: a reference to the asset identity
+    /// * dataset: the dataset name or ID (e.g. Source)
+    /// * data_files: list of local files to be uploaded
+    /// * skip_unchanged: if true, unchanged files will be skipped
+    /// * checksum_algorithm: (optional) the algorithm for the file checksums. If None, the configured one will be used
+    pub async fn upload_asset_files(
+        &mut self,
+        identity: &AssetIdentity,
+        dataset: &str,
+        data_files: Vec<&PathBuf>,
+        skip_unchanged: bool,
+        checksum_algorithm: Option<ChecksumAlgorithm>,
+    ) -> Result<Vec<FileUploadResult>, ApiError> {
+        self.init().await?;
+        log::trace!("Uploading files to asset {}...", identity.id());
+        let checksum_algorithm =
+            checksum_algorithm.unwrap_or_else(|| self.configuration.borrow().checksum_algorithm());
+
+        match &self.client {
+            Some(client) => {
+                let asset = client
+                    .get_asset(identity)
+                    .await?
+                    .ok_or(ApiError::AssetNotFound)?;
+                let dataset_id = Self::dataset_id(&asset, dataset)?;
+                let existing_files = client.list_files(identity, &dataset_id).await?;
+
+                let mut names: Vec<String> = Vec::new();
+                for path in data_files.iter() {
+                    let name = path
+                        .file_name()
+                        .unwrap_or_default()
+                        .to_string_lossy()
+                        .to_string();
+                    if names.contains(&name) {
+                        return Err(ApiError::DuplicateFilePath(name));
+                    }
+                    names.push(name);
+                }
+
+                let mut results: Vec<FileUploadResult> = Vec::new();
+                for (path, name) in data_files.into_iter().zip(names) {
+                    let existing = existing_files.iter().find(|f| f.path().eq(&name));
+
+                    let status = match existing {
+                        Some(existing) => {
+                            if skip_unchanged && Self::is_unchanged(existing, path)? {
+                                log::trace!("File {} is unchanged", name);
+                                UploadStatus::Skipped
+                            } else {
+                                Self::replace_file(
+                                    client,
+                                    identity,
+                                    &dataset_id,
+                                    path,
+                                    &name,
+                                    &checksum_algorithm,
+                                )
+                                .await?;
+                                UploadStatus::Replaced
+                            }
+                        }
+                        None => {
+                            client
+                                .upload_file(identity, &dataset_id, path, &checksum_algorithm)
+                                .await?;
+                            UploadStatus::Uploaded
+                        }
+                    };
+
+                    results.push(FileUploadResult { path: name, status });
+                }
+
+                Ok(results)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Replaces the content of an existing file in a dataset.
+    ///
+    /// Files cannot be overwritten or renamed, so the new content is first uploaded to a temporary path
+    /// next to the file. Only once that upload succeeded, the file is deleted, uploaded again and the
+    /// temporary file removed. If the second upload fails, the new content is kept in the temporary file.
+    async fn replace_file(
+        client: &Client,
+        identity: &AssetIdentity,
+        dataset_id: &String,
+        path: &Path,
+        file_name: &str,
+        checksum_algorithm: &ChecksumAlgorithm,
+    ) -> Result<(), ApiError> {
+        let temporary_name = format!("{}{}", file_name, REPLACEMENT_SUFFIX);
+        if let Err(e) = client
+            .upload_file_as(
+                identity,
+                dataset_id,
+                path,
+                &temporary_name,
+                checksum_algorithm,
+            )
+            .await
+        {
+            // the record may exist even if the upload failed
+            let _ = client
+                .delete_file(identity, dataset_id, &temporary_name)
+                .await;
+            return Err(e.into());
+        }
+
+        client.delete_file(identity, dataset_id, file_name).await?;
+        if let Err(e) = client
+            .upload_file_as(identity, dataset_id, path, file_name, checksum_algorithm)
+            .await
+        {
+            log::error!("Failed to upload {}: {}", file_name, e);
+            return Err(ApiError::ReplaceFailed(
+                file_name.to_owned(),
+                temporary_name,
+            ));
+        }
+        client
+            .delete_file(identity, dataset_id, &temporary_name)
+            .await?;
+        Ok(())
+    }
+
+    /// Returns true if a local file has the same size and checksum as an existing asset file.
+    /// Files without a remote checksum are always considered changed.
+    fn is_unchanged(remote: &AssetFile, path: &Path) -> Result<bool, ApiError> {
+        if remote.size() != Some(fs::metadata(path)?.len()) {
+            return Ok(false);
+        }
+        match remote.user_checksum() {
+            Some(remote_checksum) => match ChecksumAlgorithm::detect(&remote_checksum) {
+                Some(algorithm) => {
+                    Ok(remote_checksum.eq_ignore_ascii_case(&algorithm.compute(path)?))
+                }
+                None => Ok(false),
+            },
+            None => Ok(false),
+        }
+    }
+
+    /// Returns groups of files with identical content hashes that belong to different assets in the project.
+    ///
+    /// Files without a user checksum are ignored.
+    pub async fn find_duplicates(&mut self) -> Result<Vec<DuplicateGroup>, ApiError> {
+        self.init().await?;
+        log::trace!("Searching for duplicate files...");
+
+        match &self.client {
+            Some(client) => {
+                let assets = client.search_asset(None, None).await?;
+
+                let listings: Vec<Result<(Asset, Vec<AssetFile>), ApiError>> = stream::iter(assets)
+                    .map(|asset| async move {
+                        let asset = match asset.datasets() {
+                            Some(_) => asset,
+                            None => client
+                                .get_asset(&asset.identity())
+                                .await?
+                                .ok_or(ApiError::AssetNotFound)?,
+                        };
+                        let files = Self::asset_files(client, &asset).await?;
+                        Ok((asset, files))
+                    })
+                    .buffer_unordered(DEFAULT_CONCURRENCY)
+                    .collect()
+                    .await;
+
+                let mut groups: HashMap<String, DuplicateGroup> = HashMap::new();
+                for listing in listings {
+                    let (asset, files) = listing?;
+                    for file in files {
+                        if let Some(checksum) = file.user_checksum() {
+                            let checksum = checksum.to_lowercase();
+                            let group = groups.entry(checksum.to_owned()).or_insert_with(|| {
+                                DuplicateGroup {
+                                    checksum,
+                                    size: file.size(),
+                                    files: Vec::new(),
+                                }
+                            });
+                            group.files.push(DuplicateFile {
+                                asset: asset.identity(),
+                                asset_name: asset.name(),
+                                path: file.path(),
+                            });
+                        }
+                    }
+                }
+
+                let mut duplicates: Vec<DuplicateGroup> = groups
+                    .into_values()
+                    .filter(|group| {
+                        group
+                            .files
+                            .iter()
+                            .any(|f| f.asset.id() != group.files[0].asset.id())
+                    })
+                    .collect();
+                duplicates.sort_by(|a, b| a.checksum.cmp(&b.checksum));
+
+                Ok(duplicates)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Returns the files in all datasets of an asset. Files shared by several datasets are only listed once.
+    async fn asset_files(client: &Client, asset: &Asset) -> Result<Vec<AssetFile>, ApiError> {
+        let mut files: Vec<AssetFile> = Vec::new();
+        for dataset in asset.datasets().unwrap_or_default() {
+            for file in client.list_files(&asset.identity(), &dataset.id()).await? {
+                if !files.iter().any(|f| f.path().eq(&file.path())) {
+                    files.push(file);
+                }
+            }
+        }
+        Ok(files)
+    }
+
+    /// Recursively collects all files under a directory along with their paths relative to the root.
+    fn collect_local_files(
+        root: &Path,
+        directory: &Path,
+        files: &mut Vec<(String, PathBuf)>,
+    ) -> Result<(), ApiError> {
+        for entry in fs::read_dir(directory)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                Self::collect_local_files(root, &path, files)?;
+            } else {
+                let relative = path
+                    .strip_prefix(root)
+                    .unwrap_or(&path)
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy().to_string())
+                    .collect::<Vec<String>>()
+                    .join("/");
+                files.push((relative, path));
+            }
+        }
+        Ok(())
+    }
+
+    /// Renders a preview image of a local STL, OBJ, PLY or glTF file into a PNG file without a GPU.
+    ///
+    /// Returns the number of rendered triangles.
+    ///
+    /// Parameters:
+    /// * source: the geometry file
+    /// * size: the width and height of the image in pixels
+    /// * output: the PNG file
+    pub fn render_preview(source: &Path, size: u32, output: &Path) -> Result<usize, ApiError> {
+        log::trace!("Rendering {}...", source.to_string_lossy());
+        let mesh = Mesh::load(source)?;
+        preview::render_png(&mesh, size, output)?;
+        Ok(mesh.triangles().len())
+    }
+
+    /// Renders a preview image of an asset, uploads it to the asset's preview dataset and sets it
+    /// as the asset preview. An existing image with the same name in the dataset is replaced.
+    ///
+    /// If no local geometry file is provided, the asset files are downloaded to a temporary directory
+    /// and the first STL, OBJ, PLY or glTF file is rendered.
+    ///
+    /// Parameters:
+    /// * identity: the asset identity
+    /// * data_file: (optional) the local geometry file
+    /// * size: the width and height of the image in pixels
+    /// * output: (optional) where to keep the PNG file. If None, a temporary file is used
+    pub async fn render_asset_preview(
+        &mut self,
+        identity: &AssetIdentity,
+        data_file: Option<&Path>,
+        size: u32,
+        output: Option<&Path>,
+    ) -> Result<PreviewRenderResult, ApiError> {
+        self.init().await?;
+        let checksum_algorithm = self.configuration.borrow().checksum_algorithm();
+
+        match &self.client {
+            Some(client) => {
+                let asset = match client.get_asset(identity).await? {
+                    Some(asset) => asset,
+                    None => return Err(ApiError::AssetNotFound),
+                };
+                let dataset_id = match asset.preview_file_dataset_id() {
+                    Some(dataset_id) if !dataset_id.is_empty() => dataset_id,
+                    _ => Self::dataset_id(&asset, PREVIEW_DATASET)?,
+                };
+
+                let temp_dir = tempfile::tempdir()?;
+                let source = match data_file {
+                    Some(data_file) => data_file.to_path_buf(),
+                    None => {
+                        let download_dir = temp_dir.path().join("source");
+                        fs::create_dir_all(&download_dir)?;
+                        client
+                            .download_all_asset_files(identity, Some(&download_dir))
+                            .await?;
+
+                        let mut files = vec![];
+                        Self::collect_local_files(&download_dir, &download_dir, &mut files)?;
+                        files.sort();
+                        files
+                            .into_iter()
+                            .map(|(_, path)| path)
+                            .find(|path| Mesh::is_supported(path))
+                            .ok_or(ApiError::NoGeometryFile)?
+                    }
+                };
+
+                let file_name = format!(
+                    "{}.png",
+                    source.file_stem().unwrap_or_default().to_string_lossy()
+                );
+                let image = match output {
+                    Some(output) => output.to_path_buf(),
+                    None => temp_dir.path().join(&file_name),
+                };
+                let triangles = Self::render_preview(&source, size, &image)?;
+
+                let preview_file = image
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string();
+                let existing_files = client.list_files(identity, &dataset_id).await?;
+                if existing_files.iter().any(|f| f.path().eq(&preview_file)) {
+                    log::trace!("Replacing the preview in dataset {}...", dataset_id);
+                    Self::replace_file(
+                        client,
+                        identity,
+                        &dataset_id,
+                        &image,
+                        &preview_file,
+                        &checksum_algorithm,
+                    )
+                    .await?;
+                } else {
+                    log::trace!("Uploading the preview to dataset {}...", dataset_id);
+                    client
+                        .upload_file(identity, &dataset_id, &image, &checksum_algorithm)
+                        .await?;
+                }
+                let changes = AssetUpdate {
+                    preview_file: Some(preview_file.to_owned()),
+                    ..Default::default()
+                };
+                client.patch_asset(identity, &changes).await?;
+
+                Ok(PreviewRenderResult {
+                    asset: Some(identity.clone()),
+                    source: source.to_string_lossy().to_string(),
+                    triangles,
+                    output: output.map(|o| o.to_path_buf()),
+                    dataset_id: Some(dataset_id),
+                    preview_file: Some(preview_file),
+                })
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Computes the combined statistics of the STL, OBJ, PLY and glTF files. Other files are ignored.
+    /// Returns None if there is no such file.
+    ///
+    /// Parameters:
+    /// * files: the local files
+    pub fn model_stats(files: &[&Path]) -> Result<Option<ModelStats>, ApiError> {
+        let stats = files
+            .iter()
+            .filter(|f| ModelStats::is_supported(f))
+            .map(|f| {
+                log::trace!("Extracting the statistics of {}...", f.display());
+                ModelStats::from_file(f)
+            })
+            .collect::<Result<Vec<ModelStats>, PreviewError>>()?;
+
+        Ok(match stats.is_empty() {
+            true => None,
+            false => Some(ModelStats::combine(&stats)),
+        })
+    }
+
+    /// Extracts the statistics of the 3D model files of an asset and merges them into the asset metadata.
+    ///
+    /// If no local files are provided, the asset files are downloaded to a temporary directory.
+    /// New metadata fields are registered as number or text fields.
+    ///
+    /// Parameters:
+    /// * identity: the asset identity
+    /// * data_files: the local model files. If empty, the asset files are used
+    /// * dry_run: if true, the changes are only computed, but not applied
+    pub async fn analyze_asset(
+        &mut self,
+        identity: &AssetIdentity,
+        data_files: Vec<&Path>,
+        dry_run: bool,
+    ) -> Result<ModelAnalysisResult, ApiError> {
+        self.init().await?;
+        log::trace!("Analyzing asset {}...", identity.id());
+
+        match &self.client {
+            Some(client) => {
+                let temp_dir = tempfile::tempdir()?;
+                let stats = match data_files.is_empty() {
+                    true => {
+                        let download_dir = temp_dir.path().to_path_buf();
+                        client
+                            .download_all_asset_files(identity, Some(&download_dir))
+                            .await?;
+                        let mut files = vec![];
+                        Self::collect_local_files(&download_dir, &download_dir, &mut files)?;
+                        files.sort();
+                        let files: Vec<&Path> = files.iter().map(|(_, p)| p.as_path()).collect();
+                        let mut stats = Self::model_stats(&files)?;
+                        // report the paths relative to the asset
+                        if let Some(stats) = stats.as_mut() {
+                            stats.files = files
+                                .iter()
+                                .filter(|f| ModelStats::is_supported(f))
+                                .filter_map(|f| f.strip_prefix(&download_dir).ok())
+                                .map(|f| f.to_string_lossy().to_string())
+                                .collect();
+                        }
+                        stats
+                    }
+                    false => Self::model_stats(&data_files)?,
+                }
+                .ok_or(ApiError::NoGeometryFile)?;
+
+                let records: HashMap<String, Option<Value>> = stats
+                    .metadata()
+                    .into_iter()
+                    .map(|(k, v)| (k, Some(v)))
+                    .collect();
+                let mut definitions = Self::metadata_definitions(client, records.keys()).await?;
+                if !dry_run {
+                    Self::register_metadata_definitions(client, &mut definitions, |n| {
+                        (
+                            ModelStats::field_type(n).unwrap_or(MetadataFieldType::Text),
+                            None,
+                        )
+                    })
+                    .await?;
+                }
+                let changes = Self::apply_asset_metadata(
+                    client,
+                    &definitions,
+                    identity,
+                    records,
+                    MetadataMode::Merge,
+                    dry_run,
+                )
+                .await?;
+
+                Ok(ModelAnalysisResult {
+                    asset: identity.clone(),
+                    stats,
+                    changes,
+                })
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Starts a transformation workflow on a dataset of an asset and returns the transformation.
+    ///
+    /// Parameters:
+    /// * identity: the asset identity
+    /// * dataset: the dataset name (e.g. "Source") or ID
+    /// * workflow_type: the transformation workflow (e.g. "thumbnail-generator")
+    /// * files: the input files. If empty, all files in the dataset are used
+    /// * parameters: additional workflow parameters
+    pub async fn start_transformation(
+        &mut self,
+        identity: &AssetIdentity,
+        dataset: &str,
+        workflow_type: &str,
+        files: Vec<String>,
+        parameters: HashMap<String, Value>,
+    ) -> Result<Transformation, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let dataset_id = Self::find_dataset_id(client, identity, dataset).await?;
+                let files = match files.is_empty() {
+                    true => client
+                        .list_files(identity, &dataset_id)
+                        .await?
+                        .iter()
+                        .map(|f| f.path())
+                        .collect(),
+                    false => files,
+                };
+                if files.is_empty() {
+                    return Err(ApiError::NoFilesInDataset(dataset_id));
+                }
+
+                let request = TransformationRequest::new(files, parameters);
+                let transformation_id = client
+                    .start_transformation(identity, &dataset_id, workflow_type, &request)
+                    .await?;
+                log::trace!("Started transformation {}", transformation_id);
+
+                client
+                    .get_transformation(identity, &dataset_id, &transformation_id)
+                    .await?
+                    .ok_or(ApiError::TransformationNotFound(transformation_id))
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Returns the transformations of an asset.
+    ///
+    /// Parameters:
+    /// * identity: the asset identity
+    /// * dataset: (optional) only the transformations of this dataset (name or ID)
+    pub async fn list_transformations(
+        &mut self,
+        identity: &AssetIdentity,
+        dataset: Option<&str>,
+    ) -> Result<Vec<Transformation>, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let dataset_id = match dataset {
+                    Some(dataset) => Some(Self::find_dataset_id(client, identity, dataset).await?),
+                    None => None,
+                };
+
+                Ok(client
+                    .list_transformations(identity, dataset_id.as_deref())
+                    .await?)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Returns the details of a transformation.
+    ///
+    /// Parameters:
+    /// * identity: the asset identity
+    /// * dataset: the dataset name or ID the transformation was started on
+    /// * transformation_id: the ID of the transformation
+    pub async fn get_transformation(
+        &mut self,
+        identity: &AssetIdentity,
+        dataset: &str,
+        transformation_id: &str,
+    ) -> Result<Transformation, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let dataset_id = Self::find_dataset_id(client, identity, dataset).await?;
+
+                client
+                    .get_transformation(identity, &dataset_id, transformation_id)
+                    .await?
+                    .ok_or(ApiError::TransformationNotFound(
+                        transformation_id.to_owned(),
+                    ))
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Cancels a transformation and returns its updated details.
+    ///
+    /// Parameters:
+    /// * identity: the asset identity
+    /// * dataset: the dataset name or ID the transformation was started on
+    /// * transformation_id: the ID of the transformation
+    pub async fn cancel_transformation(
+        &mut self,
+        identity: &AssetIdentity,
+        dataset: &str,
+        transformation_id: &str,
+    ) -> Result<Transformation, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let dataset_id = Self::find_dataset_id(client, identity, dataset).await?;
+
+                match client.cancel_transformation(transformation_id).await {
+                    Ok(()) => (),
+                    Err(ClientError::NotFound) => {
+                        return Err(ApiError::TransformationNotFound(
+                            transformation_id.to_owned(),
+                        ))
+                    }
+                    Err(e) => return Err(e.into()),
+                }
+
+                client
+                    .get_transformation(identity, &dataset_id, transformation_id)
+                    .await?
+                    .ok_or(ApiError::TransformationNotFound(
+                        transformation_id.to_owned(),
+                    ))
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Waits for a transformation to finish and returns its final details.
+    ///
+    /// Parameters:
+    /// * identity: the asset identity
+    /// * dataset: the dataset name or ID the transformation was started on
+    /// * transformation_id: the ID of the transformation
+    /// * timeout: the maximum time to wait
+    pub async fn wait_transformation(
+        &mut self,
+        identity: &AssetIdentity,
+        dataset: &str,
+        transformation_id: &str,
+        timeout: Duration,
+    ) -> Result<Transformation, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let dataset_id = Self::find_dataset_id(client, identity, dataset).await?;
+
+                Self::wait_for_transformation(
+                    client,
+                    identity,
+                    &dataset_id,
+                    transformation_id,
+                    timeout,
+                )
+                .await?
+                .ok_or(ApiError::TransformationTimeout(
+                    transformation_id.to_owned(),
+                ))
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Returns all versions of an asset, the latest first.
+    ///
+    /// Parameters:
+    ///
+    /// * asset_id - the asset ID
+    pub async fn list_asset_versions(
+        &mut self,
+        asset_id: &str,
+    ) -> Result<Vec<AssetVersion>, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let mut versions = client.list_asset_versions(asset_id).await?;
+                AssetVersion::sort_latest_first(&mut versions);
+                Ok(versions)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Returns the details of an asset version. The version can be the latest keyword.
+    ///
+    /// Parameters:
+    ///
+    /// * identity - the asset identity
+    pub async fn get_asset_version(
+        &mut self,
+        identity: &AssetIdentity,
+    ) -> Result<AssetVersion, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => client
+                .get_asset_version(identity)
+                .await?
+                .ok_or(ApiError::AssetNotFound),
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Creates a new version of an asset and returns it.
+    ///
+    /// The new version starts with the datasets and files of the parent version. If keep_files is false,
+    /// the files are removed from the new version, so that it can be filled from scratch.
+    ///
+    /// Parameters:
+    ///
+    /// * identity - the asset identity with the parent version (e.g. latest)
+    /// * keep_files - if false, the new version keeps the datasets of the parent version, but not their files
+    pub async fn create_asset_version(
+        &mut self,
+        identity: &AssetIdentity,
+        keep_files: bool,
+    ) -> Result<AssetVersion, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let version = client.create_asset_version(identity).await?;
+                let new_identity = AssetIdentity::new(identity.id(), version);
+                log::trace!("Created version {}", new_identity.version());
+
+                if !keep_files {
+                    let asset = client
+                        .get_asset(&new_identity)
+                        .await?
+                        .ok_or(ApiError::AssetNotFound)?;
+                    for dataset in asset.datasets().unwrap_or_default() {
+                        for file in client.list_files(&new_identity, &dataset.id()).await? {
+                            client
+                                .delete_file(&new_identity, &dataset.id(), &file.path())
+                                .await?;
+                        }
+                    }
+                }
+
+                client
+                    .get_asset_version(&new_identity)
+                    .await?
+                    .ok_or(ApiError::AssetNotFound)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Freezes an asset version with a change log message and returns it with its version number.
+    ///
+    /// Parameters:
+    ///
+    /// * identity - the asset identity
+    /// * changelog - the description of the changes in this version
+    pub async fn freeze_asset_version(
+        &mut self,
+        identity: &AssetIdentity,
+        changelog: &str,
+    ) -> Result<AssetVersion, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                // the latest keyword is resolved before freezing, as the frozen version may not be the latest afterwards
+                let version = client
+                    .get_asset_version(identity)
+                    .await?
+                    .ok_or(ApiError::AssetNotFound)?;
+                let identity = version.identity();
+                client.freeze_asset_version(&identity, changelog).await?;
+
+                client
+                    .get_asset_version(&identity)
+                    .await?
+                    .ok_or(ApiError::AssetNotFound)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Returns the datasets of an asset.
+    ///
+    /// Parameters:
+    ///
+    /// * identity - the asset identity
+    pub async fn list_datasets(
+        &mut self,
+        identity: &AssetIdentity,
+    ) -> Result<Vec<Dataset>, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => Ok(client.list_datasets(identity).await?),
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Returns the details of a dataset of an asset.
+    ///
+    /// Parameters:
+    ///
+    /// * identity - the asset identity
+    /// * dataset - the dataset name or ID
+    pub async fn get_dataset(
+        &mut self,
+        identity: &AssetIdentity,
+        dataset: &str,
+    ) -> Result<Dataset, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let dataset_id = Self::find_dataset_id(client, identity, dataset).await?;
+                client
+                    .get_dataset(identity, &dataset_id)
+                    .await?
+                    .ok_or(ApiError::DatasetNotFound(dataset.to_owned()))
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Creates a dataset in an asset and returns it.
+    ///
+    /// Parameters:
+    ///
+    /// * identity - the asset identity
+    /// * dataset - the name, description, primary type and tags of the new dataset
+    /// * preview - if true, the new dataset is set as the preview dataset of the asset
+    pub async fn create_dataset(
+        &mut self,
+        identity: &AssetIdentity,
+        dataset: &Dataset,
+        preview: bool,
+    ) -> Result<Dataset, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let dataset_id = client.create_dataset(identity, dataset).await?;
+                log::trace!("Created dataset {}", dataset_id);
+
+                if preview {
+                    Self::set_preview_dataset(client, identity, &dataset_id).await?;
+                }
+
+                client
+                    .get_dataset(identity, &dataset_id)
+                    .await?
+                    .ok_or(ApiError::DatasetNotFound(dataset_id))
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Updates the name, description, tags and primary type of a dataset.
+    ///
+    /// The current state of the dataset is read first and only the fields that differ
+    /// from the requested state are sent to Unity.
+    ///
+    /// Parameters:
+    ///
+    /// * identity - the asset identity
+    /// * dataset - the dataset name or ID
+    /// * options - the requested changes
+    /// * preview - if true, the dataset is set as the preview dataset of the asset
+    pub async fn update_dataset(
+        &mut self,
+        identity: &AssetIdentity,
+        dataset: &str,
+        options: &DatasetUpdateOptions,
+        preview: bool,
+    ) -> Result<DatasetUpdateResult, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let asset = client
+                    .get_asset(identity)
+                    .await?
+                    .ok_or(ApiError::AssetNotFound)?;
+                let dataset_id = Self::dataset_id(&asset, dataset)?;
+                let current = client
+                    .get_dataset(identity, &dataset_id)
+                    .await?
+                    .ok_or(ApiError::DatasetNotFound(dataset.to_owned()))?;
+
+                let changes = options.changes(&current);
+                let mut updated = false;
+                if !changes.is_empty() {
+                    client
+                        .update_dataset(identity, &dataset_id, &changes)
+                        .await?;
+                    updated = true;
+                }
+
+                let preview =
+                    preview && asset.preview_file_dataset_id().as_ref() != Some(&dataset_id);
+                if preview {
+                    Self::set_preview_dataset(client, identity, &dataset_id).await?;
+                    updated = true;
+                }
+
+                Ok(DatasetUpdateResult {
+                    asset: identity.clone(),
+                    dataset_id,
+                    changes,
+                    preview,
+                    updated,
+                })
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Deletes a dataset of an asset together with its files.
+    ///
+    /// Parameters:
+    ///
+    /// * identity - the asset identity
+    /// * dataset - the dataset name or ID
+    pub async fn delete_dataset(
+        &mut self,
+        identity: &AssetIdentity,
+        dataset: &str,
+    ) -> Result<(), ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let dataset_id = Self::find_dataset_id(client, identity, dataset).await?;
+                Ok(client.delete_dataset(identity, &dataset_id).await?)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Returns the files of an asset with their sizes, checksums, tags and metadata.
+    ///
+    /// Without a dataset, the files of all datasets are returned. A file contained in several datasets
+    /// is returned once, with the IDs of all its datasets.
+    ///
+    /// Parameters:
+    ///
+    /// * identity - the asset identity
+    /// * dataset - (optional) the dataset name or ID
+    pub async fn list_files(
+        &mut self,
+        identity: &AssetIdentity,
+        dataset: Option<&str>,
+    ) -> Result<Vec<AssetFile>, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let asset = client
+                    .get_asset(identity)
+                    .await?
+                    .ok_or(ApiError::AssetNotFound)?;
+                let dataset_ids = match dataset {
+                    Some(dataset) => vec![Self::dataset_id(&asset, dataset)?],
+                    None => asset
+                        .datasets()
+                        .unwrap_or_default()
+                        .iter()
+                        .map(|d| d.id())
+                        .collect(),
+                };
+
+                let mut files: Vec<AssetFile> = vec![];
+                for dataset_id in dataset_ids {
+                    for mut file in client.list_files(identity, &dataset_id).await? {
+                        match files.iter_mut().find(|f| f.path() == file.path()) {
+                            Some(existing) => {
+                                let mut ids = existing.dataset_ids();
+                                for id in file
+                                    .dataset_ids()
+                                    .into_iter()
+                                    .chain([dataset_id.to_owned()])
+                                {
+                                    if !ids.contains(&id) {
+                                        ids.push(id);
+                                    }
+                                }
+                                existing.set_dataset_ids(ids);
+                            }
+                            None => {
+                                if !file.dataset_ids().contains(&dataset_id) {
+                                    let mut ids = file.dataset_ids();
+                                    ids.push(dataset_id.to_owned());
+                                    file.set_dataset_ids(ids);
+                                }
+                                files.push(file);
+                            }
+                        }
+                    }
+                }
+
+                Ok(files)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Returns the details of a file of an asset.
+    ///
+    /// Parameters:
+    ///
+    /// * identity - the asset identity
+    /// * dataset - the dataset name or ID
+    /// * path - the file path in the dataset
+    pub async fn get_file(
+        &mut self,
+        identity: &AssetIdentity,
+        dataset: &str,
+        path: &str,
+    ) -> Result<AssetFile, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let dataset_id = Self::find_dataset_id(client, identity, dataset).await?;
+                match client.get_file(identity, &dataset_id, path).await {
+                    Ok(file) => Ok(file),
+                    Err(ClientError::NotFound) => Err(ApiError::FileNotFound(path.to_owned())),
+                    Err(e) => Err(e.into()),
+                }
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Updates the description, tags and metadata of a file.
+    ///
+    /// The current state of the file is read first and only the fields that differ
+    /// from the requested state are sent to Unity. Metadata fields that are not yet defined
+    /// in the organization are registered first.
+    ///
+    /// Parameters:
+    ///
+    /// * identity - the asset identity
+    /// * dataset - the dataset name or ID
+    /// * path - the file path in the dataset
+    /// * options - the requested changes
+    pub async fn update_file(
+        &mut self,
+        identity: &AssetIdentity,
+        dataset: &str,
+        path: &str,
+        options: &AssetFileUpdateOptions,
+    ) -> Result<AssetFileUpdateResult, ApiError> {
+        self.init().await?;
+
+        match &self.client {
+            Some(client) => {
+                let dataset_id = Self::find_dataset_id(client, identity, dataset).await?;
+                let file = match client.get_file(identity, &dataset_id, path).await {
+                    Ok(file) => file,
+                    Err(ClientError::NotFound) => {
+                        return Err(ApiError::FileNotFound(path.to_owned()))
+                    }
+                    Err(e) => return Err(e.into()),
+                };
+
+                let mut options = options.clone();
+                if !options.metadata.is_empty() {
+                    let values = options.metadata;
+                    let mut definitions = Self::metadata_definitions(client, values.keys()).await?;
+                    Self::register_metadata_definitions(client, &mut definitions, |n| {
+                        Self::default_field(values.get(n))
+                    })
+                    .await?;
+                    options.metadata = Self::coerce_metadata(&definitions, values)?;
+                }
+
+                let changes = options.changes(&file);
+                let updated = !changes.is_empty();
+                if updated {
+                    client
+                        .update_file(identity, &dataset_id, path, &changes)
+                        .await?;
+                }
+
+                Ok(AssetFileUpdateResult {
+                    asset: identity.clone(),
+                    dataset_id,
+                    path: path.to_owned(),
+                    changes,
+                    updated,
+                })
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
+
+    /// Sets the dataset whose files provide the preview of the asset.
+    async fn set_preview_dataset(
+        client: &Client,
+        identity: &AssetIdentity,
+        dataset_id: &str,
+    ) -> Result<(), ApiError> {
+        let changes = AssetUpdate {
+            preview_file_dataset_id: Some(dataset_id.to_owned()),
+            ..Default::default()
+        };
+        Ok(client.patch_asset(identity, &changes).await?)
+    }
+
+    /// Returns the ID of an asset dataset given its name or ID. A missing dataset is created.
+    async fn find_or_create_dataset(
+        client: &Client,
+        identity: &AssetIdentity,
+        dataset: &str,
+        primary_type: String,
+    ) -> Result<String, ApiError> {
+        match Self::find_dataset_id(client, identity, dataset).await {
+            Err(ApiError::DatasetNotFound(_)) => {
+                log::trace!("Creating dataset {}...", dataset);
+                let dataset = Dataset::new(String::new(), dataset.to_owned(), Some(primary_type));
+                Ok(client.create_dataset(identity, &dataset).await?)
+            }
+            result => result,
+        }
+    }
+
+    /// Returns the ID of an asset dataset given its name or ID.
+    async fn find_dataset_id(
+        client: &Client,
+        identity: &AssetIdentity,
+        dataset: &str,
+    ) -> Result<String, ApiError> {
+        match client.get_asset(identity).await? {
+            Some(asset) => Self::dataset_id(&asset, dataset),
+            None => Err(ApiError::AssetNotFound),
+        }
+    }
+
+    /// Returns the ID of a dataset of the asset given its name (case-insensitive) or ID.
+    fn dataset_id(asset: &Asset, dataset: &str) -> Result<String, ApiError> {
+        asset
+            .datasets()
+            .unwrap_or_default()
+            .iter()
+            .find(|d| d.id() == dataset || d.name().eq_ignore_ascii_case(dataset))
+            .map(|d| d.id())
+            .ok_or(ApiError::DatasetNotFound(dataset.to_owned()))
     }
 
-    /// Generates thumbnails and previews for the specified asset..
+    /// Generates thumbnails for the specified asset or for all assets in the project that do not have one.
+    ///
+    /// The thumbnail generator transformation is started on the files of the Source dataset of each asset
+    /// without a preview. If a timeout is provided, each transformation is polled until it completes,
+    /// fails or the timeout expires. Returns the result for each asset.
     ///
     /// Parameters:
     ///
     /// asset_id - (optional) the asset identity (id, version). If not provided, it will attempt to generate thumbnails for all assets in the project that do not have one.
+    /// wait - (optional) the maximum time to wait for each transformation. If None, the transformations are only started.
     pub async fn generate_asset_thumbnail(
         &mut self,
         asset_id: Option<AssetIdentity>,
-    ) -> Result<Vec<Asset>, ApiError> {
+        wait: Option<Duration>,
+    ) -> Result<Vec<ThumbnailGenerationResult>, ApiError> {
         self.init().await?;
 
         match &self.client {
             Some(client) => {
                 let assets = client.search_asset(asset_id, None).await?;
 
-                for asset in assets.clone() {
-                    log::trace!(
-                        "Verifying thumbnails for asset id={}, version={}...",
-                        asset.identity().id(),
-                        asset.identity().version()
-                    );
+                let results = stream::iter(assets)
+                    .map(|asset| async move {
+                        log::trace!(
+                            "Verifying thumbnails for asset id={}, version={}...",
+                            asset.identity().id(),
+                            asset.identity().version()
+                        );
 
-                    match asset.preview_file() {
-                        None => {
-                            log::trace!("This asset does not have a thumbnail!");
+                        if asset.preview_file().is_some_and(|p| !p.is_empty()) {
+                            return ThumbnailGenerationResult {
+                                asset: asset.identity(),
+                                name: asset.name(),
+                                transformation_id: None,
+                                status: ThumbnailGenerationStatus::Skipped,
+                                error: None,
+                            };
+                        }
 
-                            let preview_file_dataset_id =
-                                asset.preview_file_dataset_id().unwrap_or_default();
+                        log::trace!("This asset does not have a thumbnail!");
+                        Self::generate_thumbnail(client, &asset, wait).await
+                    })
+                    .buffered(DEFAULT_CONCURRENCY)
+                    .collect::<Vec<ThumbnailGenerationResult>>()
+                    .await;
 
-                            log::trace!(
-                                "The preview dataset ID is {}",
-                                preview_file_dataset_id.to_owned()
-                            );
+                Ok(results)
+            }
+            None => Err(ApiError::ClientNotInitialized),
+        }
+    }
 
-                            //let files: Vec<String> = Vec::new();
+    /// Starts the thumbnail generator for an asset and optionally waits for it to finish.
+    ///
+    /// Errors are reported in the result, along with the transformation ID if the generator was started.
+    async fn generate_thumbnail(
+        client: &Client,
+        asset: &Asset,
+        wait: Option<Duration>,
+    ) -> ThumbnailGenerationResult {
+        let result = |transformation_id: Option<String>,
+                      status: ThumbnailGenerationStatus,
+                      error: Option<String>| ThumbnailGenerationResult {
+            asset: asset.identity(),
+            name: asset.name(),
+            transformation_id,
+            status,
+            error,
+        };
 
-                            /*
-                            client
-                                .generate_thumbnails(
-                                    &asset.identity(),
-                                    &preview_file_dataset_id,
-                                    files,
-                                )
-                                .await?;
-                            */
-                        }
-                        Some(_) => (),
-                    }
+        let (dataset_id, transformation_id) = match Self::start_thumbnail_generation(client, asset)
+            .await
+        {
+            Ok(started) => started,
+            Err(e) => return result(None, ThumbnailGenerationStatus::Failed, Some(e.to_string())),
+        };
+
+        let timeout = match wait {
+            Some(timeout) => timeout,
+            None => {
+                return result(
+                    Some(transformation_id),
+                    ThumbnailGenerationStatus::Started,
+                    None,
+                )
+            }
+        };
+
+        let (status, error) = match Self::wait_for_transformation(
+            client,
+            &asset.identity(),
+            &dataset_id,
+            &transformation_id,
+            timeout,
+        )
+        .await
+        {
+            Ok(Some(transformation)) => match transformation.status() {
+                TransformationStatus::Succeeded => (ThumbnailGenerationStatus::Succeeded, None),
+                status => (
+                    ThumbnailGenerationStatus::Failed,
+                    Some(
+                        transformation
+                            .error_message()
+                            .unwrap_or_else(|| format!("transformation {}", status)),
+                    ),
+                ),
+            },
+            Ok(None) => (ThumbnailGenerationStatus::TimedOut, None),
+            Err(e) => (ThumbnailGenerationStatus::Failed, Some(e.to_string())),
+        };
+        result(Some(transformation_id), status, error)
+    }
+
+    /// Starts the thumbnail generator on the files of the Source dataset of an asset.
+    /// Returns the dataset ID and the transformation ID.
+    async fn start_thumbnail_generation(
+        client: &Client,
+        asset: &Asset,
+    ) -> Result<(String, String), ApiError> {
+        let identity = asset.identity();
+        let dataset_id = Self::dataset_id(asset, SOURCE_DATASET_NAME)?;
+
+        let files: Vec<String> = client
+            .list_files(&identity, &dataset_id)
+            .await?
+            .iter()
+            .map(|f| f.path())
+            .collect();
+        if files.is_empty() {
+            return Err(ApiError::NoFilesInDataset(dataset_id));
+        }
+
+        let transformation_id = client
+            .generate_thumbnails(&identity, &dataset_id, files)
+            .await?;
+        log::trace!(
+            "Started thumbnail generation {} for asset {}",
+            transformation_id,
+            identity.id()
+        );
+
+        Ok((dataset_id, transformation_id))
+    }
+
+    /// Polls a transformation until it is finished. Returns None if the timeout expired before that.
+    async fn wait_for_transformation(
+        client: &Client,
+        identity: &AssetIdentity,
+        dataset_id: &str,
+        transformation_id: &str,
+        timeout: Duration,
+    ) -> Result<Option<Transformation>, ApiError> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            match client
+                .get_transformation(identity, dataset_id, transformation_id)
+                .await?
+            {
+                Some(transformation) if transformation.status().is_finished() => {
+                    return Ok(Some(transformation))
+                }
+                Some(transformation) => log::trace!(
+                    "Transformation {} is {}...",
+                    transformation_id,
+                    transformation.status()
+                ),
+                None => {
+                    return Err(ApiError::TransformationNotFound(
+                        transformation_id.to_owned(),
+                    ))
                 }
+            }
 
-                Ok(assets)
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(None);
             }
-            None => Err(ApiError::ClientNotInitialized),
+            tokio::time::sleep(TRANSFORMATION_POLL_INTERVAL.min(deadline - now)).await;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn values(value: Value) -> HashMap<String, Value> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn combine_metadata_modes() {
+        let current = values(json!({"a": "1", "b": "2"}));
+        let uploaded = || -> HashMap<String, Option<Value>> {
+            HashMap::from([("b".to_string(), None), ("c".to_string(), Some(json!("3")))])
+        };
+
+        assert_eq!(
+            Api::combine_metadata(&current, uploaded(), MetadataMode::Replace),
+            values(json!({"c": "3"}))
+        );
+        assert_eq!(
+            Api::combine_metadata(&current, uploaded(), MetadataMode::Merge),
+            values(json!({"a": "1", "b": "2", "c": "3"}))
+        );
+        assert_eq!(
+            Api::combine_metadata(&current, uploaded(), MetadataMode::Patch),
+            values(json!({"a": "1", "c": "3"}))
+        );
+    }
+
+    #[test]
+    fn metadata_changes_are_sorted_by_key() {
+        let before = values(json!({"b": "1", "a": "1", "same": true}));
+        let after = values(json!({"b": "2", "c": "3", "same": true}));
+
+        let changes = Api::metadata_changes(&before, &after);
+        assert_eq!(
+            changes,
+            vec![
+                MetadataChange {
+                    key: "a".to_string(),
+                    before: Some(json!("1")),
+                    after: None,
+                },
+                MetadataChange {
+                    key: "b".to_string(),
+                    before: Some(json!("1")),
+                    after: Some(json!("2")),
+                },
+                MetadataChange {
+                    key: "c".to_string(),
+                    before: None,
+                    after: Some(json!("3")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn coerce_metadata_uses_definitions() {
+        let mut definitions = HashMap::new();
+        definitions.insert(
+            "Count".to_string(),
+            Some(MetadataDefinition::new(
+                "Count".to_string(),
+                "Count".to_string(),
+                MetadataFieldType::Number,
+                None,
+            )),
+        );
+        definitions.insert("New".to_string(), None);
+
+        let coerced = Api::coerce_metadata(
+            &definitions,
+            values(json!({"Count": "3", "New": 12, "Tags": ["a", 1], "Other": "x"})),
+        )
+        .unwrap();
+        assert_eq!(
+            coerced,
+            values(json!({"Count": 3, "New": "12", "Tags": ["a", "1"], "Other": "x"}))
+        );
+    }
+
+    #[test]
+    fn coerce_metadata_rejects_invalid_values() {
+        let mut definitions = HashMap::new();
+        definitions.insert(
+            "Count".to_string(),
+            Some(MetadataDefinition::new(
+                "Count".to_string(),
+                "Count".to_string(),
+                MetadataFieldType::Number,
+                None,
+            )),
+        );
+
+        assert!(Api::coerce_metadata(&definitions, values(json!({"Count": "many"}))).is_err());
+    }
+
+    #[test]
+    fn infer_field_type_from_column_values() {
+        let infer = |values: &[&str]| Api::infer_field_type(values.iter().copied());
+        assert_eq!(infer(&["12.5", "3"]), MetadataFieldType::Number);
+        assert_eq!(infer(&["true", "No"]), MetadataFieldType::Boolean);
+        assert_eq!(
+            infer(&["2024-05-01", "2024-05-01T10:00:00Z"]),
+            MetadataFieldType::Timestamp
+        );
+        assert_eq!(infer(&["12.5", "n/a"]), MetadataFieldType::Text);
+        assert_eq!(infer(&[]), MetadataFieldType::Text);
+    }
+
+    #[test]
+    fn flatten_metadata_joins_nested_keys() {
+        let mut flattened = HashMap::new();
+        Api::flatten_metadata(
+            None,
+            json!({
+                "material": "steel",
+                "dimensions": {"width": 10, "size": {"unit": "mm"}},
+                "tags": ["a", "b"]
+            }),
+            ".",
+            &mut flattened,
+        );
+        assert_eq!(
+            flattened,
+            values(json!({
+                "material": "steel",
+                "dimensions.width": 10,
+                "dimensions.size.unit": "mm",
+                "tags": ["a", "b"]
+            }))
+        );
+    }
+
+    #[test]
+    fn flatten_metadata_ignores_top_level_scalars() {
+        let mut flattened = HashMap::new();
+        Api::flatten_metadata(None, json!("value"), ".", &mut flattened);
+        assert!(flattened.is_empty());
+    }
+
+    #[test]
+    fn read_asset_identities_formats() {
+        let input = r#"# assets to update
+id1
+id2,3
+id3 4
+
+{"id": "id4", "version": 5}
+{"identity": {"id": "id5", "version": "6"}, "name": "Bolt"}
+[{"asset": {"id": "id6"}}, {"id": "id7", "version": "2"}]
+"#;
+
+        let identities = Api::read_asset_identities(input.as_bytes(), "1").unwrap();
+        let identities: Vec<(String, String)> = identities
+            .into_iter()
+            .map(|i| (i.id(), i.version()))
+            .collect();
+        assert_eq!(
+            identities,
+            vec![
+                ("id1".to_string(), "1".to_string()),
+                ("id2".to_string(), "3".to_string()),
+                ("id3".to_string(), "4".to_string()),
+                ("id4".to_string(), "5".to_string()),
+                ("id5".to_string(), "6".to_string()),
+                ("id6".to_string(), "1".to_string()),
+                ("id7".to_string(), "2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn read_asset_identities_rejects_objects_without_id() {
+        let result = Api::read_asset_identities(r#"{"name": "Bolt"}"#.as_bytes(), "1");
+        assert!(matches!(result, Err(ApiError::InvalidAssetIdentity(_))));
+    }
+
+    fn file(path: &str, size: Option<u64>, checksum: Option<&str>) -> AssetFile {
+        AssetFile::new(path.to_string(), size, checksum.map(String::from), None)
+    }
+
+    #[test]
+    fn file_changes_by_path() {
+        let before = vec![
+            file("a.stl", Some(10), Some("AAAA")),
+            file("b.stl", Some(10), Some("bbbb")),
+            file("c.stl", Some(10), None),
+            file("removed.stl", Some(1), None),
+        ];
+        let after = vec![
+            file("added.stl", Some(2), Some("cccc")),
+            file("a.stl", Some(10), Some("aaaa")),
+            file("b.stl", Some(10), Some("dddd")),
+            file("c.stl", Some(11), Some("eeee")),
+        ];
+
+        let changes: Vec<(String, FileChangeStatus)> = Api::file_changes("Source", &before, &after)
+            .into_iter()
+            .map(|c| (c.path, c.status))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                ("added.stl".to_string(), FileChangeStatus::Added),
+                ("b.stl".to_string(), FileChangeStatus::Modified),
+                ("c.stl".to_string(), FileChangeStatus::Modified),
+                ("removed.stl".to_string(), FileChangeStatus::Removed),
+            ]
+        );
+    }
+
+    #[test]
+    fn file_changes_keep_sizes_and_checksums() {
+        let before = vec![file("a.stl", Some(10), Some("aaaa"))];
+        let after = vec![file("a.stl", Some(12), Some("bbbb"))];
+
+        assert_eq!(
+            Api::file_changes("Source", &before, &after),
+            vec![FileChange {
+                dataset: "Source".to_string(),
+                path: "a.stl".to_string(),
+                status: FileChangeStatus::Modified,
+                size_before: Some(10),
+                size_after: Some(12),
+                checksum_before: Some("aaaa".to_string()),
+                checksum_after: Some("bbbb".to_string()),
+            }]
+        );
+    }
+}
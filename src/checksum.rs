@@ -0,0 +1,175 @@
+//! Implements local file checksum computation.
+//!
+//! Checksums are sent to Unity as the user checksum of a file during upload and are
+//! later used to verify the integrity of the remote file records.
+use md5::Md5;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+    str::FromStr,
+};
+use thiserror::Error;
+
+/// A wrapper for all errors generated when parsing a checksum algorithm name.
+#[derive(Debug, Error)]
+pub struct ChecksumAlgorithmParseError(String);
+
+impl std::fmt::Display for ChecksumAlgorithmParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Failed to parse checksum algorithm value of {}", self.0)
+    }
+}
+
+/// Supported checksum algorithms.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChecksumAlgorithm {
+    #[default]
+    Md5,
+    Sha256,
+}
+
+impl std::fmt::Display for ChecksumAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ChecksumAlgorithm::Md5 => "md5",
+                ChecksumAlgorithm::Sha256 => "sha256",
+            }
+        )
+    }
+}
+
+impl FromStr for ChecksumAlgorithm {
+    type Err = ChecksumAlgorithmParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.to_lowercase();
+        match value.as_ref() {
+            "md5" => Ok(ChecksumAlgorithm::Md5),
+            "sha256" | "sha-256" => Ok(ChecksumAlgorithm::Sha256),
+            _ => Err(ChecksumAlgorithmParseError(value.to_string())),
+        }
+    }
+}
+
+impl ChecksumAlgorithm {
+    /// Guesses the algorithm that produced a hex-encoded checksum based on its length.
+    ///
+    /// Unity does not store the algorithm along with the user checksum, so this is
+    /// the only way to tell which one to use when verifying an existing file.
+    ///
+    /// Parameters:
+    ///
+    /// * checksum: the hex-encoded checksum value
+    pub fn detect(checksum: &str) -> Option<ChecksumAlgorithm> {
+        match checksum.len() {
+            32 => Some(ChecksumAlgorithm::Md5),
+            64 => Some(ChecksumAlgorithm::Sha256),
+            _ => None,
+        }
+    }
+
+    /// Computes the hex-encoded checksum for a local file.
+    ///
+    /// Parameters:
+    ///
+    /// * path: path to the local file
+    pub fn compute(&self, path: &Path) -> Result<String, std::io::Error> {
+        let file = File::open(path)?;
+        match self {
+            ChecksumAlgorithm::Md5 => Self::digest::<Md5>(file),
+            ChecksumAlgorithm::Sha256 => Self::digest::<Sha256>(file),
+        }
+    }
+
+    /// Streams the file content through the hasher.
+    fn digest<D: Digest>(file: File) -> Result<String, std::io::Error> {
+        let mut reader = BufReader::new(file);
+        let mut hasher = D::new();
+        let mut buffer = [0u8; 64 * 1024];
+        loop {
+            let count = reader.read(&mut buffer)?;
+            if count == 0 {
+                break;
+            }
+            hasher.update(&buffer[..count]);
+        }
+        Ok(hex::encode(hasher.finalize()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn detect_by_length() {
+        assert_eq!(
+            ChecksumAlgorithm::detect("900150983cd24fb0d6963f7d28e17f72"),
+            Some(ChecksumAlgorithm::Md5)
+        );
+        assert_eq!(
+            ChecksumAlgorithm::detect(
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+            ),
+            Some(ChecksumAlgorithm::Sha256)
+        );
+    }
+
+    #[test]
+    fn detect_unknown_length() {
+        assert_eq!(ChecksumAlgorithm::detect(""), None);
+        assert_eq!(ChecksumAlgorithm::detect("abc"), None);
+        assert_eq!(
+            ChecksumAlgorithm::detect("da39a3ee5e6b4b0d3255bfef95601890afd80709"),
+            None
+        );
+    }
+
+    fn checksum_of(algorithm: ChecksumAlgorithm, content: &[u8]) -> String {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(content).unwrap();
+        algorithm.compute(file.path()).unwrap()
+    }
+
+    #[test]
+    fn compute_md5() {
+        assert_eq!(
+            checksum_of(ChecksumAlgorithm::Md5, b"abc"),
+            "900150983cd24fb0d6963f7d28e17f72"
+        );
+        assert_eq!(
+            checksum_of(ChecksumAlgorithm::Md5, b""),
+            "d41d8cd98f00b204e9800998ecf8427e"
+        );
+    }
+
+    #[test]
+    fn compute_sha256() {
+        assert_eq!(
+            checksum_of(ChecksumAlgorithm::Sha256, b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            checksum_of(ChecksumAlgorithm::Sha256, b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn compute_large_file() {
+        // larger than the read buffer, so that the content is hashed in several chunks
+        let content = vec![b'a'; 200 * 1024];
+        assert_eq!(
+            checksum_of(ChecksumAlgorithm::Md5, &content),
+            "87803ac1cabd226eeb9c7665efe2a758"
+        );
+    }
+}
@@ -1,14 +1,17 @@
 /// This module defines the available structure of command line commands and their arguments as well
 /// as the method to parse and execute the command.
 use crate::{
-    api::Api,
+    api::{Api, ApiError, AssetCreateOptions, AssetFileUpdateOptions, AssetSelection, AssetUpdateOptions, DatasetUpdateOptions, DEFAULT_METADATA_KEY_SEPARATOR, DEFAULT_TRANSFORMATION_TIMEOUT, SOURCE_DATASET_NAME},
+    checksum::ChecksumAlgorithm,
     configuration::{Configuration, ConfigurationError},
-    model::{AssetIdentity, AssetStatus},
+    preview::DEFAULT_PREVIEW_SIZE,
+    model::{AssetDiff, AssetIdentity, AssetStatus, Dataset, LATEST_VERSION, MetadataDefinition, MetadataDefinitionUpdate, MetadataFieldType, MetadataFormat, MetadataMode, PreviewRenderResult},
 };
-use clap::{Arg, ArgAction, ArgMatches, Command};
-use std::path::PathBuf;
+use clap::{Arg, ArgAction, ArgGroup, ArgMatches, Command};
+use std::{collections::HashMap, fs::File, io::BufReader, path::{Path, PathBuf}, time::Duration};
 use thiserror::Error;
 
+#[derive(Default)]
 pub struct Cli {}
 
 const COMMAND_CONFIG: &str = "config";
@@ -28,6 +31,30 @@ const COMMAND_UPLOAD: &str = "upload";
 const COMMAND_STATUS: &str = "status";
 const COMMAND_METADATA: &str = "metadata";
 const COMMAND_GENERATE_THUMBNAIL: &str = "generate-thumbnail";
+const COMMAND_VERIFY: &str = "verify";
+const COMMAND_CHECKSUM: &str = "checksum";
+const COMMAND_DUPLICATES: &str = "duplicates";
+const COMMAND_TYPE: &str = "type";
+const COMMAND_UPDATE: &str = "update";
+const COMMAND_FIELD: &str = "field";
+const COMMAND_LIST: &str = "list";
+const COMMAND_SCHEMA: &str = "schema";
+const COMMAND_APPLY: &str = "apply";
+const COMMAND_IMPORT: &str = "import";
+const COMMAND_TRANSFORM: &str = "transform";
+const COMMAND_START: &str = "start";
+const COMMAND_CANCEL: &str = "cancel";
+const COMMAND_WAIT: &str = "wait";
+const COMMAND_PREVIEW: &str = "preview";
+const COMMAND_RENDER: &str = "render";
+const COMMAND_ANALYZE: &str = "analyze";
+const COMMAND_VALIDATE: &str = "validate";
+const COMMAND_DATASET: &str = "dataset";
+const COMMAND_FILE: &str = "file";
+const COMMAND_VERSION: &str = "version";
+const COMMAND_LATEST: &str = "latest";
+const COMMAND_FREEZE: &str = "freeze";
+const COMMAND_DIFF: &str = "diff";
 
 const PARAMETER_OUTPUT: &str = "output";
 const PARAMETER_DOWNLOAD_DIR: &str = "download-dir";
@@ -45,8 +72,48 @@ const PARAMETER_STATUS: &str = "status";
 const PARAMETER_PUBLISH: &str = "publish";
 const PARAMETER_ASSET_NAME: &str = "asset-name";
 const PARAMETER_METADATA_KEY: &str = "meta";
+const PARAMETER_CHECKSUM: &str = "checksum";
+const PARAMETER_ALGORITHM: &str = "algorithm";
+const PARAMETER_SKIP_UNCHANGED: &str = "skip-unchanged";
+const PARAMETER_TYPE: &str = "type";
+const PARAMETER_EXTENSION: &str = "extension";
+const PARAMETER_TAG: &str = "tag";
+const PARAMETER_METADATA_FILE: &str = "meta-file";
+const PARAMETER_ADD_TAG: &str = "add-tag";
+const PARAMETER_REMOVE_TAG: &str = "remove-tag";
+const PARAMETER_MODE: &str = "mode";
+const PARAMETER_DRY_RUN: &str = "dry-run";
+const PARAMETER_DISPLAY_NAME: &str = "display-name";
+const PARAMETER_ACCEPTED_VALUE: &str = "accepted-value";
+const PARAMETER_SCHEMA: &str = "schema";
+const PARAMETER_FORMAT: &str = "format";
+const PARAMETER_SEPARATOR: &str = "separator";
+const PARAMETER_SIDECAR: &str = "sidecar";
+const PARAMETER_TO: &str = "to";
+const PARAMETER_FROM: &str = "from";
+const PARAMETER_HAS_THUMBNAIL: &str = "has-thumbnail";
+const PARAMETER_IDS_FILE: &str = "ids-file";
+const PARAMETER_STDIN: &str = "stdin";
+const PARAMETER_WAIT: &str = "wait";
+const PARAMETER_NO_WAIT: &str = "no-wait";
+const PARAMETER_TIMEOUT: &str = "timeout";
+const PARAMETER_WORKFLOW: &str = "workflow";
+const PARAMETER_DATASET: &str = "dataset";
+const PARAMETER_FILE: &str = "file";
+const PARAMETER_PARAM: &str = "param";
+const PARAMETER_TRANSFORMATION_ID: &str = "transformation-id";
+const PARAMETER_SIZE: &str = "size";
+const PARAMETER_NO_UPLOAD: &str = "no-upload";
+const PARAMETER_EXTRACT_STATS: &str = "extract-stats";
+const PARAMETER_NO_VALIDATE: &str = "no-validate";
+const PARAMETER_INCLUDE_REFERENCES: &str = "include-references";
+const PARAMETER_PREVIEW: &str = "preview";
+const PARAMETER_MESSAGE: &str = "message";
+const PARAMETER_NO_FILES: &str = "no-files";
+const PARAMETER_LOCAL: &str = "local";
+const PARAMETER_APPLY: &str = "apply";
 
-const BANNER: &'static str = r#"
+const BANNER: &str = r#"
 ╦ ╦╔═╗╔╦╗  ╔═╗╦  ╦
 ║ ║╠═╣║║║  ║  ║  ║
 ╚═╝╩ ╩╩ ╩  ╚═╝╩═╝╩
@@ -63,12 +130,16 @@ pub enum CliError {
     ApiError(#[from] crate::api::ApiError),
     #[error("Asset staus parse error")]
     StatusParseError(#[from] crate::model::AssetStatusParseError),
-}
-
-impl Default for Cli {
-    fn default() -> Cli {
-        Cli {}
-    }
+    #[error("Checksum algorithm parse error")]
+    ChecksumAlgorithmParseError(#[from] crate::checksum::ChecksumAlgorithmParseError),
+    #[error("Metadata mode parse error")]
+    MetadataModeParseError(#[from] crate::model::MetadataModeParseError),
+    #[error("Metadata format parse error")]
+    MetadataFormatParseError(#[from] crate::model::MetadataFormatParseError),
+    #[error("Metadata field type parse error")]
+    MetadataFieldTypeParseError(#[from] crate::model::MetadataFieldTypeParseError),
+    #[error("Invalid argument value: {0}")]
+    InvalidArgument(String),
 }
 
 /// Command Line Interface abstraction.
@@ -119,7 +190,7 @@ impl Cli {
             .long(PARAMETER_ASSET_VERSION)
             .required(false)
             .default_value("1")
-            .help("Asset version");
+            .help("Asset version, or latest for the latest version of the asset");
         let metadata_key_parameter = Arg::new(PARAMETER_METADATA_KEY)
             .long(PARAMETER_METADATA_KEY)
             .required(true)
@@ -127,6 +198,35 @@ impl Cli {
             .value_delimiter(',')
             .action(clap::ArgAction::Append)
             .help("Metadata property name");
+        let field_name_parameter = Arg::new(PARAMETER_NAME)
+            .long(PARAMETER_NAME)
+            .required(true)
+            .help("Metadata field name");
+        let display_name_parameter = Arg::new(PARAMETER_DISPLAY_NAME)
+            .long(PARAMETER_DISPLAY_NAME)
+            .required(false)
+            .help("Metadata field name as displayed in the Asset Manager UI");
+        let accepted_value_parameter = Arg::new(PARAMETER_ACCEPTED_VALUE)
+            .long(PARAMETER_ACCEPTED_VALUE)
+            .required(false)
+            .num_args(1..)
+            .value_delimiter(',')
+            .action(ArgAction::Append)
+            .help("Accepted value for single-select and multi-select fields. Can be repeated or comma-delimited");
+
+        let dataset_parameter = Arg::new(PARAMETER_DATASET)
+            .long(PARAMETER_DATASET)
+            .required(false)
+            .default_value(SOURCE_DATASET_NAME)
+            .help("Dataset name or ID");
+        let transformation_id_parameter = Arg::new(PARAMETER_TRANSFORMATION_ID)
+            .required(true)
+            .help("Transformation ID");
+        let timeout_parameter = Arg::new(PARAMETER_TIMEOUT)
+            .long(PARAMETER_TIMEOUT)
+            .required(false)
+            .value_parser(clap::value_parser!(u64))
+            .help(format!("Maximum time in seconds to wait [default: {}]", DEFAULT_TRANSFORMATION_TIMEOUT.as_secs()));
 
         Command::new(env!("CARGO_PKG_NAME"))
             .version(env!("CARGO_PKG_VERSION"))
@@ -168,10 +268,64 @@ impl Cli {
                                     .about("Prints the default configuration file path")
                             )
                     )
+                    .subcommand(
+                        Command::new(COMMAND_CHECKSUM)
+                            .about("Checksum algorithm used for uploaded files")
+                            .subcommand_required(true)
+                            .subcommand(
+                                Command::new(COMMAND_SET)
+                                    .about("Sets the default checksum algorithm")
+                                    .arg(
+                                        Arg::new(PARAMETER_ALGORITHM)
+                                            .long(PARAMETER_ALGORITHM)
+                                            .required(true)
+                                            .help("Checksum algorithm (md5 or sha256)")
+                                    )
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_GET)
+                                    .about("Prints the default checksum algorithm")
+                            )
+                    )
+                    .subcommand(
+                        Command::new(COMMAND_TYPE)
+                            .about("Mapping of file extensions to asset primary types")
+                            .subcommand_required(true)
+                            .subcommand(
+                                Command::new(COMMAND_SET)
+                                    .about("Maps a file extension to an asset primary type")
+                                    .arg(
+                                        Arg::new(PARAMETER_EXTENSION)
+                                            .long(PARAMETER_EXTENSION)
+                                            .required(true)
+                                            .help("File extension (e.g. fbx)")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_TYPE)
+                                            .long(PARAMETER_TYPE)
+                                            .required(true)
+                                            .help("Asset primary type (e.g. \"3D Model\")")
+                                    )
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_GET)
+                                    .about("Prints the current mapping")
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_DELETE)
+                                    .about("Removes the mapping for a file extension")
+                                    .arg(
+                                        Arg::new(PARAMETER_EXTENSION)
+                                            .long(PARAMETER_EXTENSION)
+                                            .required(true)
+                                            .help("File extension (e.g. fbx)")
+                                    )
+                            )
+                    )
                     .subcommand(
                         Command::new(COMMAND_EXPORT)
                             .about("Exports the current configuration in a file")
-                            .arg(output_file_parameter),
+                            .arg(output_file_parameter.clone()),
                     )
                     .subcommand(
                         Command::new(COMMAND_DELETE).about("Deletes the configuration file"),
@@ -218,8 +372,8 @@ impl Cli {
                             .arg(
                                 Arg::new(PARAMETER_NAME)
                                     .long(PARAMETER_NAME)
-                                    .required(true)
-                                    .help("asset name"),
+                                    .required_unless_present(PARAMETER_SIDECAR)
+                                    .help("asset name. Optional with --sidecar if a sidecar file provides the name"),
                             )
                             .arg(
                                 Arg::new(PARAMETER_DESCRIPTION)
@@ -227,6 +381,33 @@ impl Cli {
                                     .required(false)
                                     .help("Asset description"),
                             )
+                            .arg(
+                                Arg::new(PARAMETER_TYPE)
+                                    .long(PARAMETER_TYPE)
+                                    .required(false)
+                                    .help("Optional: Asset primary type (e.g. \"3D Model\"). If not provided, it is detected from the file extensions")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_TAG)
+                                    .long(PARAMETER_TAG)
+                                    .required(false)
+                                    .action(clap::ArgAction::Append)
+                                    .help("Optional: Asset tag. Can be repeated")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_METADATA_KEY)
+                                    .long(PARAMETER_METADATA_KEY)
+                                    .required(false)
+                                    .action(clap::ArgAction::Append)
+                                    .help("Optional: Metadata value in the form key=value. Can be repeated")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_METADATA_FILE)
+                                    .long(PARAMETER_METADATA_FILE)
+                                    .required(false)
+                                    .help("Optional: File containing metadata in CSV (Name, Value), JSON or YAML format")
+                                    .value_parser(clap::value_parser!(PathBuf)),
+                            )
                             .arg(
                                 Arg::new(PARAMETER_DATA_FILE)
                                     .long(PARAMETER_DATA_FILE)
@@ -241,6 +422,226 @@ impl Cli {
                                     .required(false)
                                     .action(ArgAction::SetTrue)
                                     .help("If present, the asset will be automatically published after creation")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_CHECKSUM)
+                                    .long(PARAMETER_CHECKSUM)
+                                    .required(false)
+                                    .help("Optional: Checksum algorithm for the uploaded files (md5 or sha256). Defaults to the configured algorithm")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_SIDECAR)
+                                    .long(PARAMETER_SIDECAR)
+                                    .required(false)
+                                    .action(ArgAction::SetTrue)
+                                    .help("If present, sidecar metadata files next to the data files (e.g. part.step.meta.json) are applied to the asset")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_EXTRACT_STATS)
+                                    .long(PARAMETER_EXTRACT_STATS)
+                                    .required(false)
+                                    .action(ArgAction::SetTrue)
+                                    .help("If present, the statistics of the STL, OBJ, PLY and glTF data files (triangles, vertices, dimensions, materials, textures) are stored as metadata")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_NO_VALIDATE)
+                                    .long(PARAMETER_NO_VALIDATE)
+                                    .required(false)
+                                    .action(ArgAction::SetTrue)
+                                    .help("If present, the data files are not validated before the asset is created")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_INCLUDE_REFERENCES)
+                                    .long(PARAMETER_INCLUDE_REFERENCES)
+                                    .required(false)
+                                    .action(ArgAction::SetTrue)
+                                    .conflicts_with(PARAMETER_NO_VALIDATE)
+                                    .help("If present, the files referenced by the data files (e.g. OBJ materials, textures, glTF buffers) are uploaded as well")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_DATASET)
+                                    .long(PARAMETER_DATASET)
+                                    .required(false)
+                                    .help("Optional: Dataset the data files are uploaded to. It is created if it does not exist. Defaults to Source")
+                            ),
+                    )
+                    .subcommand(
+                        Command::new(COMMAND_VALIDATE)
+                            .about("Validates data files before upload: readability, known extensions, 3D model consistency and referenced files")
+                            .arg(
+                                Arg::new(PARAMETER_DATA_FILE)
+                                    .long(PARAMETER_DATA_FILE)
+                                    .required(true)
+                                    .action(ArgAction::Append)
+                                    .help("Data file to be validated")
+                                    .value_parser(clap::value_parser!(PathBuf)),
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_INCLUDE_REFERENCES)
+                                    .long(PARAMETER_INCLUDE_REFERENCES)
+                                    .required(false)
+                                    .action(ArgAction::SetTrue)
+                                    .help("If present, the referenced files are added to the data files instead of being reported as errors")
+                            ),
+                    )
+                    .subcommand(
+                        Command::new(COMMAND_UPDATE)
+                            .about("Updates the name, description, tags and type of asset(s)")
+                            .arg(
+                                Arg::new(PARAMETER_ASSET_ID)
+                                    .long(PARAMETER_ASSET_ID)
+                                    .num_args(1..)
+                                    .value_delimiter(',')
+                                    .action(clap::ArgAction::Append)
+                                    .help("Asset ID. Multiple values can be provided to update several assets")
+                            )
+                            .arg(asset_version_parameter.clone())
+                            .arg(
+                                Arg::new(PARAMETER_ASSET_NAME)
+                                    .long(PARAMETER_ASSET_NAME)
+                                    .required(false)
+                                    .help("Updates all assets whose name matches this value")
+                            )
+                            .group(
+                                ArgGroup::new("selection")
+                                    .args([PARAMETER_ASSET_ID, PARAMETER_ASSET_NAME])
+                                    .required(true)
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_NAME)
+                                    .long(PARAMETER_NAME)
+                                    .required(false)
+                                    .help("Optional: New asset name. Only allowed if a single asset is selected"),
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_DESCRIPTION)
+                                    .long(PARAMETER_DESCRIPTION)
+                                    .required(false)
+                                    .help("Optional: New asset description"),
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_ADD_TAG)
+                                    .long(PARAMETER_ADD_TAG)
+                                    .required(false)
+                                    .action(clap::ArgAction::Append)
+                                    .help("Optional: Tag to be added. Can be repeated")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_REMOVE_TAG)
+                                    .long(PARAMETER_REMOVE_TAG)
+                                    .required(false)
+                                    .action(clap::ArgAction::Append)
+                                    .help("Optional: Tag to be removed. Can be repeated")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_TYPE)
+                                    .long(PARAMETER_TYPE)
+                                    .required(false)
+                                    .help("Optional: New asset primary type (e.g. \"3D Model\")")
+                            ),
+                    )
+                    .subcommand(
+                        Command::new(COMMAND_UPLOAD)
+                            .about("Uploads files into an existing asset")
+                            .arg(asset_id_parameter.clone())
+                            .arg(asset_version_parameter.clone())
+                            .arg(dataset_parameter.clone())
+                            .arg(
+                                Arg::new(PARAMETER_DATA_FILE)
+                                    .long(PARAMETER_DATA_FILE)
+                                    .required(true)
+                                    .action(clap::ArgAction::Append)
+                                    .help("File to be uploaded")
+                                    .value_parser(clap::value_parser!(PathBuf)),
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_SKIP_UNCHANGED)
+                                    .long(PARAMETER_SKIP_UNCHANGED)
+                                    .required(false)
+                                    .action(ArgAction::SetTrue)
+                                    .help("If present, files with the same size and checksum as the existing asset files will not be uploaded")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_CHECKSUM)
+                                    .long(PARAMETER_CHECKSUM)
+                                    .required(false)
+                                    .help("Optional: Checksum algorithm for the uploaded files (md5 or sha256). Defaults to the configured algorithm")
+                            ),
+                    )
+                    .subcommand(
+                        Command::new(COMMAND_DUPLICATES)
+                            .about("Reports assets in the project whose files have identical content hashes")
+                    )
+                    .subcommand(
+                        Command::new(COMMAND_VERIFY)
+                            .about("Compares local files against the checksums and sizes stored in Unity")
+                            .arg(asset_id_parameter.clone())
+                            .arg(asset_version_parameter.clone())
+                            .arg(
+                                Arg::new(PARAMETER_DATA_FILE)
+                                    .long(PARAMETER_DATA_FILE)
+                                    .required(true)
+                                    .action(clap::ArgAction::Append)
+                                    .help("Local file or directory to verify")
+                                    .value_parser(clap::value_parser!(PathBuf)),
+                            ),
+                    )
+                    .subcommand(
+                        Command::new(COMMAND_DIFF)
+                            .about("Compares two versions of an asset (fields, metadata, datasets and files), or a local directory with the asset files")
+                            .arg(asset_id_parameter.clone())
+                            .arg(
+                                Arg::new(PARAMETER_FROM)
+                                    .long(PARAMETER_FROM)
+                                    .required_unless_present(PARAMETER_LOCAL)
+                                    .conflicts_with(PARAMETER_LOCAL)
+                                    .help("Older asset version")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_TO)
+                                    .long(PARAMETER_TO)
+                                    .required(false)
+                                    .default_value(LATEST_VERSION)
+                                    .conflicts_with(PARAMETER_LOCAL)
+                                    .help("Newer asset version")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_LOCAL)
+                                    .long(PARAMETER_LOCAL)
+                                    .required(false)
+                                    .help("Local directory to compare with the asset files")
+                                    .value_parser(clap::value_parser!(PathBuf)),
+                            )
+                            .arg(
+                                asset_version_parameter
+                                    .clone()
+                                    .default_value(LATEST_VERSION)
+                                    .help("Asset version to compare the local directory with, or latest for the latest version of the asset"),
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_DATASET)
+                                    .long(PARAMETER_DATASET)
+                                    .required(false)
+                                    .requires(PARAMETER_LOCAL)
+                                    .conflicts_with(PARAMETER_FROM)
+                                    .help("Dataset name or ID to compare the local directory with [default: all datasets, new files go to Source]")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_APPLY)
+                                    .long(PARAMETER_APPLY)
+                                    .required(false)
+                                    .action(ArgAction::SetTrue)
+                                    .requires(PARAMETER_LOCAL)
+                                    .conflicts_with(PARAMETER_FROM)
+                                    .help("Upload new and modified local files into a new asset version")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_FORMAT)
+                                    .long(PARAMETER_FORMAT)
+                                    .required(false)
+                                    .value_parser(["table", "json"])
+                                    .default_value("table")
+                                    .help("Output format")
                             ),
                     )
                     .subcommand(
@@ -273,19 +674,120 @@ impl Cli {
                             .about("Status operations on an asset")
                             .subcommand(
                                 Command::new(COMMAND_SET)
-                                    .arg(asset_id_parameter.clone())
+                                    .about("Moves one or many assets to the target status through the shortest sequence of allowed transitions")
+                                    .arg(
+                                        Arg::new(PARAMETER_ASSET_ID)
+                                            .long(PARAMETER_ASSET_ID)
+                                            .required(false)
+                                            .num_args(1..)
+                                            .value_delimiter(',')
+                                            .action(ArgAction::Append)
+                                            .help("Asset ID. Multiple values can be provided to change several assets")
+                                    )
                                     .arg(asset_version_parameter.clone())
                                     .arg(
-                                        Arg::new(PARAMETER_STATUS)
-                                            .long(PARAMETER_STATUS)
+                                        Arg::new(PARAMETER_ASSET_NAME)
+                                            .long(PARAMETER_ASSET_NAME)
+                                            .required(false)
+                                            .help("Selects all assets whose name contains this value")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_IDS_FILE)
+                                            .long(PARAMETER_IDS_FILE)
+                                            .required(false)
+                                            .value_parser(clap::value_parser!(PathBuf))
+                                            .help("File with one asset per line: an asset ID optionally followed by the version, or a JSON object")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_STDIN)
+                                            .long(PARAMETER_STDIN)
+                                            .action(ArgAction::SetTrue)
+                                            .help("Reads the assets from the standard input in the same format as --ids-file (e.g. NDJSON from a search)")
+                                    )
+                                    .group(
+                                        ArgGroup::new("selection")
+                                            .args([PARAMETER_ASSET_ID, PARAMETER_ASSET_NAME, PARAMETER_IDS_FILE, PARAMETER_STDIN, PARAMETER_FROM, PARAMETER_HAS_THUMBNAIL, PARAMETER_METADATA_KEY])
+                                            .multiple(true)
+                                            .required(true)
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_FROM)
+                                            .long(PARAMETER_FROM)
+                                            .required(false)
+                                            .help("Optional: Only assets currently having this status")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_HAS_THUMBNAIL)
+                                            .long(PARAMETER_HAS_THUMBNAIL)
+                                            .action(ArgAction::SetTrue)
+                                            .help("Optional: Only assets with a thumbnail")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_METADATA_KEY)
+                                            .long(PARAMETER_METADATA_KEY)
+                                            .required(false)
+                                            .num_args(1..)
+                                            .value_delimiter(',')
+                                            .action(ArgAction::Append)
+                                            .help("Optional: Only assets with a value for this metadata field. Can be repeated or comma-delimited")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_TO)
+                                            .long(PARAMETER_TO)
+                                            .alias(PARAMETER_STATUS)
                                             .required(true)
-                                            .help("Asset status value (e.g. draft, inreview, approved, published, rejected, withdrawn)")
+                                            .help("Target asset status value (e.g. draft, inreview, approved, published, rejected, withdrawn)")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_DRY_RUN)
+                                            .long(PARAMETER_DRY_RUN)
+                                            .action(ArgAction::SetTrue)
+                                            .help("Shows the transitions without changing any asset")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_FORMAT)
+                                            .long(PARAMETER_FORMAT)
+                                            .required(false)
+                                            .value_parser(["table", "json"])
+                                            .default_value("table")
+                                            .help("Output format when several assets are selected")
                                     ),
                             ),
                     )
                     .subcommand(
                         Command::new(COMMAND_METADATA)
                             .about("Metadata operations")
+                            .subcommand(
+                                Command::new(COMMAND_GET)
+                                    .about("Returns the metadata of an asset")
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone()),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_EXPORT)
+                                    .about("Exports the metadata of the assets matching the search criteria to a CSV file with one row per asset and one column per field")
+                                    .arg(output_file_parameter.clone())
+                                    .arg(Arg::new(PARAMETER_ASSET_ID)
+                                        .help("Optional: The asset ID")
+                                        .long(PARAMETER_ASSET_ID)
+                                        .required(false)
+                                        .action(ArgAction::Set)
+                                    )
+                                    .arg(Arg::new(PARAMETER_ASSET_VERSION)
+                                        .help("Asset version. Required if an asset ID is provided")
+                                        .long(PARAMETER_ASSET_VERSION)
+                                        .requires(PARAMETER_ASSET_ID)
+                                        .required(false)
+                                        .default_value("1")
+                                        .action(ArgAction::Set)
+                                    )
+                                    .arg(Arg::new(PARAMETER_ASSET_NAME)
+                                        .help("Optional: The name of the asset")
+                                        .long(PARAMETER_ASSET_NAME)
+                                        .required(false)
+                                        .action(ArgAction::Set)
+                                    ),
+                            )
                             .subcommand(
                                 Command::new(COMMAND_UPLOAD)
                                     .about("Uploads metadata for an asset")
@@ -296,8 +798,60 @@ impl Cli {
                                             .long(PARAMETER_DATA_FILE)
                                             .required(true)
                                             .action(clap::ArgAction::Append)
-                                            .help("File containing the metadata in CSV format with two columns: NAME, VALUE")
+                                            .help("File containing the metadata in CSV format with two columns: NAME, VALUE or a JSON/YAML object")
+                                            .value_parser(clap::value_parser!(PathBuf)),
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_FORMAT)
+                                            .long(PARAMETER_FORMAT)
+                                            .required(false)
+                                            .help("Format of the metadata file: csv, json or yaml. Detected from the file extension if not provided")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_SEPARATOR)
+                                            .long(PARAMETER_SEPARATOR)
+                                            .required(false)
+                                            .default_value(DEFAULT_METADATA_KEY_SEPARATOR)
+                                            .help("Separator used to join the keys of nested JSON/YAML objects into field names")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_MODE)
+                                            .long(PARAMETER_MODE)
+                                            .required(false)
+                                            .default_value("merge")
+                                            .help("How the values are combined with the existing metadata: merge (keep existing fields), replace (discard existing fields) or patch (like merge, but empty values delete fields)")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_DRY_RUN)
+                                            .long(PARAMETER_DRY_RUN)
+                                            .required(false)
+                                            .action(ArgAction::SetTrue)
+                                            .help("If present, only prints the changes without applying them")
+                                    ),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_IMPORT)
+                                    .about("Imports metadata for many assets from a manifest with one row per asset and one column per field")
+                                    .arg(
+                                        Arg::new(PARAMETER_DATA_FILE)
+                                            .long(PARAMETER_DATA_FILE)
+                                            .required(true)
+                                            .help("Manifest file in CSV, XLSX, XLS or ODS format. Assets are identified by the AssetId and AssetVersion columns or by the AssetName column")
                                             .value_parser(clap::value_parser!(PathBuf)),
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_MODE)
+                                            .long(PARAMETER_MODE)
+                                            .required(false)
+                                            .default_value("merge")
+                                            .help("How the values are combined with the existing metadata: merge (keep existing fields), replace (discard existing fields) or patch (like merge, but empty values delete fields)")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_DRY_RUN)
+                                            .long(PARAMETER_DRY_RUN)
+                                            .required(false)
+                                            .action(ArgAction::SetTrue)
+                                            .help("If present, only prints the changes without applying them")
                                     ),
                             )
                             .subcommand(
@@ -325,41 +879,504 @@ impl Cli {
                                 .default_value("1")
                                 .action(ArgAction::Set)
                                 
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_WAIT)
+                                    .long(PARAMETER_WAIT)
+                                    .action(ArgAction::SetTrue)
+                                    .help("Waits for the thumbnail generation to complete (default)")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_NO_WAIT)
+                                    .long(PARAMETER_NO_WAIT)
+                                    .action(ArgAction::SetTrue)
+                                    .conflicts_with(PARAMETER_WAIT)
+                                    .help("Only starts the thumbnail generation")
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_TIMEOUT)
+                                    .long(PARAMETER_TIMEOUT)
+                                    .required(false)
+                                    .value_parser(clap::value_parser!(u64))
+                                    .conflicts_with(PARAMETER_NO_WAIT)
+                                    .help(format!("Maximum time in seconds to wait for each asset [default: {}]", DEFAULT_TRANSFORMATION_TIMEOUT.as_secs()))
                             ),
                     )
-            )
-            .get_matches()
-    }
-
-    /// Parses the command line arguments and executes matching commands.
-    /// Returns Ok(()) if successfule or CliError otherwise.
-    ///
-    /// # Arguments
-    ///
-    /// * api - configured API object
-    ///
-    pub async fn execute_command(&self, mut api: Api) -> Result<(), CliError> {
-        match self.prepare_commands().subcommand() {
-            // configuration commands and their parameters
-            Some((COMMAND_CONFIG, sub_matches)) => match sub_matches.subcommand() {
-                Some((COMMAND_PATH, sub_matches)) => match sub_matches.subcommand() {
-                    Some((COMMAND_GET, _)) => {
-                        let path = Configuration::get_default_configuration_file_path()?;
-                        let path = path.into_os_string().into_string().unwrap();
-                        println!("{}", path);
-                    }
-                    _ => unreachable!("Invalid command"),
-                },
-                Some((COMMAND_CLIENT, sub_matches)) => match sub_matches.subcommand() {
-                    Some((COMMAND_SET, sub_matches)) => {
-                        let organization_id = sub_matches
-                            .get_one::<String>(PARAMETER_ORGANIZATION)
-                            .unwrap();
-                        let project_id =
-                            sub_matches.get_one::<String>(PARAMETER_PROJECT_ID).unwrap(); // unwraps here are safe, because the arguments is mandatory and it will caught by Clap before this point
-                        let environment_id = sub_matches
-                            .get_one::<String>(PARAMETER_ENVIRONMENT_ID)
-                            .unwrap();
+                    .subcommand(
+                        Command::new(COMMAND_PREVIEW)
+                            .about("Preview image operations")
+                            .subcommand_required(true)
+                            .subcommand(
+                                Command::new(COMMAND_RENDER)
+                                    .about("Renders a preview image of a STL, OBJ, PLY or glTF file without a GPU and sets it as the asset preview")
+                                    .arg(
+                                        Arg::new(PARAMETER_ASSET_ID)
+                                            .long(PARAMETER_ASSET_ID)
+                                            .required_unless_present(PARAMETER_NO_UPLOAD)
+                                            .help("Asset ID")
+                                    )
+                                    .arg(asset_version_parameter.clone())
+                                    .arg(
+                                        Arg::new(PARAMETER_DATA_FILE)
+                                            .long(PARAMETER_DATA_FILE)
+                                            .required_if_eq(PARAMETER_NO_UPLOAD, "true")
+                                            .help("Optional: Local geometry file. If not provided, the first STL, OBJ, PLY or glTF file of the asset is downloaded and rendered")
+                                            .value_parser(clap::value_parser!(PathBuf)),
+                                    )
+                                    .arg(
+                                        output_file_parameter
+                                            .clone()
+                                            .required_if_eq(PARAMETER_NO_UPLOAD, "true")
+                                            .required(false)
+                                            .help("Optional: Path of the PNG file to keep. Required with --no-upload")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_SIZE)
+                                            .long(PARAMETER_SIZE)
+                                            .required(false)
+                                            .value_parser(clap::value_parser!(u32).range(16..=4096))
+                                            .help(format!("Width and height of the image in pixels [default: {}]", DEFAULT_PREVIEW_SIZE))
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_NO_UPLOAD)
+                                            .long(PARAMETER_NO_UPLOAD)
+                                            .action(ArgAction::SetTrue)
+                                            .help("Only renders the image to the output file")
+                                    ),
+                            ),
+                    )
+                    .subcommand(
+                        Command::new(COMMAND_ANALYZE)
+                            .about("Extracts the statistics of the 3D model files of an asset and stores them as metadata")
+                            .arg(
+                                Arg::new(PARAMETER_ASSET_ID)
+                                    .long(PARAMETER_ASSET_ID)
+                                    .required(true)
+                                    .help("Asset ID")
+                            )
+                            .arg(asset_version_parameter.clone())
+                            .arg(
+                                Arg::new(PARAMETER_DATA_FILE)
+                                    .long(PARAMETER_DATA_FILE)
+                                    .required(false)
+                                    .action(ArgAction::Append)
+                                    .help("Optional: Local model file. If not provided, the asset files are downloaded and analyzed")
+                                    .value_parser(clap::value_parser!(PathBuf)),
+                            )
+                            .arg(
+                                Arg::new(PARAMETER_DRY_RUN)
+                                    .long(PARAMETER_DRY_RUN)
+                                    .action(ArgAction::SetTrue)
+                                    .help("Only prints the statistics and the metadata changes without applying them")
+                            ),
+                    )
+                    .subcommand(
+                        Command::new(COMMAND_TRANSFORM)
+                            .about("Transformation workflows (e.g. thumbnail generation, mesh optimization, format conversion)")
+                            .subcommand_required(true)
+                            .subcommand(
+                                Command::new(COMMAND_START)
+                                    .about("Starts a transformation workflow on a dataset of an asset")
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone())
+                                    .arg(dataset_parameter.clone())
+                                    .arg(
+                                        Arg::new(PARAMETER_WORKFLOW)
+                                            .long(PARAMETER_WORKFLOW)
+                                            .required(true)
+                                            .help("Transformation workflow type (e.g. thumbnail-generator)")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_FILE)
+                                            .long(PARAMETER_FILE)
+                                            .required(false)
+                                            .num_args(1..)
+                                            .value_delimiter(',')
+                                            .action(ArgAction::Append)
+                                            .help("Optional: Input file path in the dataset. Can be repeated or comma-delimited. If not provided, all files in the dataset are used")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_PARAM)
+                                            .long(PARAMETER_PARAM)
+                                            .required(false)
+                                            .action(ArgAction::Append)
+                                            .help("Optional: Workflow parameter in the form name=value. Can be repeated")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_WAIT)
+                                            .long(PARAMETER_WAIT)
+                                            .action(ArgAction::SetTrue)
+                                            .help("Waits for the transformation to finish")
+                                    )
+                                    .arg(timeout_parameter.clone().requires(PARAMETER_WAIT)),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_LIST)
+                                    .about("Lists the transformations of an asset")
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone())
+                                    .arg(
+                                        Arg::new(PARAMETER_DATASET)
+                                            .long(PARAMETER_DATASET)
+                                            .required(false)
+                                            .help("Optional: Only the transformations of this dataset (name or ID)")
+                                    ),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_STATUS)
+                                    .about("Returns the details of a transformation")
+                                    .arg(transformation_id_parameter.clone())
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone())
+                                    .arg(dataset_parameter.clone()),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_CANCEL)
+                                    .about("Cancels a transformation")
+                                    .arg(transformation_id_parameter.clone())
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone())
+                                    .arg(dataset_parameter.clone()),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_WAIT)
+                                    .about("Waits for a transformation to finish")
+                                    .arg(transformation_id_parameter.clone())
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone())
+                                    .arg(dataset_parameter.clone())
+                                    .arg(timeout_parameter.clone()),
+                            ),
+                    )
+                    .subcommand(
+                        Command::new(COMMAND_DATASET)
+                            .about("Datasets of an asset (e.g. Source, Preview, LODs)")
+                            .subcommand_required(true)
+                            .subcommand(
+                                Command::new(COMMAND_LIST)
+                                    .about("Lists the datasets of an asset")
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone()),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_GET)
+                                    .about("Returns the details of a dataset")
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone())
+                                    .arg(dataset_parameter.clone().required(true).default_value(None)),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_CREATE)
+                                    .about("Creates a new dataset in an asset")
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone())
+                                    .arg(
+                                        Arg::new(PARAMETER_NAME)
+                                            .long(PARAMETER_NAME)
+                                            .required(true)
+                                            .help("Dataset name"),
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_DESCRIPTION)
+                                            .long(PARAMETER_DESCRIPTION)
+                                            .required(false)
+                                            .help("Optional: Dataset description"),
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_TYPE)
+                                            .long(PARAMETER_TYPE)
+                                            .required(false)
+                                            .help("Optional: Dataset primary type (e.g. \"3D Model\")")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_TAG)
+                                            .long(PARAMETER_TAG)
+                                            .required(false)
+                                            .action(ArgAction::Append)
+                                            .help("Optional: Dataset tag. Can be repeated")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_PREVIEW)
+                                            .long(PARAMETER_PREVIEW)
+                                            .action(ArgAction::SetTrue)
+                                            .help("If present, the dataset is set as the preview dataset of the asset")
+                                    ),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_UPDATE)
+                                    .about("Updates the name, description, tags and type of a dataset")
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone())
+                                    .arg(dataset_parameter.clone().required(true).default_value(None))
+                                    .arg(
+                                        Arg::new(PARAMETER_NAME)
+                                            .long(PARAMETER_NAME)
+                                            .required(false)
+                                            .help("Optional: New dataset name"),
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_DESCRIPTION)
+                                            .long(PARAMETER_DESCRIPTION)
+                                            .required(false)
+                                            .help("Optional: New dataset description"),
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_ADD_TAG)
+                                            .long(PARAMETER_ADD_TAG)
+                                            .required(false)
+                                            .action(ArgAction::Append)
+                                            .help("Optional: Tag to be added. Can be repeated")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_REMOVE_TAG)
+                                            .long(PARAMETER_REMOVE_TAG)
+                                            .required(false)
+                                            .action(ArgAction::Append)
+                                            .help("Optional: Tag to be removed. Can be repeated")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_TYPE)
+                                            .long(PARAMETER_TYPE)
+                                            .required(false)
+                                            .help("Optional: New dataset primary type (e.g. \"3D Model\")")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_PREVIEW)
+                                            .long(PARAMETER_PREVIEW)
+                                            .action(ArgAction::SetTrue)
+                                            .help("If present, the dataset is set as the preview dataset of the asset")
+                                    ),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_DELETE)
+                                    .about("Deletes a dataset and its files")
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone())
+                                    .arg(dataset_parameter.clone().required(true).default_value(None)),
+                            ),
+                    )
+                    .subcommand(
+                        Command::new(COMMAND_VERSION)
+                            .about("Versions of an asset")
+                            .subcommand_required(true)
+                            .subcommand(
+                                Command::new(COMMAND_LIST)
+                                    .about("Lists the versions of an asset, the latest first")
+                                    .arg(asset_id_parameter.clone()),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_LATEST)
+                                    .about("Returns the latest version of an asset")
+                                    .arg(asset_id_parameter.clone()),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_GET)
+                                    .about("Returns the details of an asset version")
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone()),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_CREATE)
+                                    .about("Creates a new version of an asset with the datasets and files of a parent version")
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(
+                                        asset_version_parameter
+                                            .clone()
+                                            .default_value(LATEST_VERSION)
+                                            .help("Parent version, or latest for the latest version of the asset")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_NO_FILES)
+                                            .long(PARAMETER_NO_FILES)
+                                            .action(ArgAction::SetTrue)
+                                            .help("If present, the new version keeps the datasets of the parent version, but not their files")
+                                    ),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_FREEZE)
+                                    .about("Freezes an asset version with a change log message. A frozen version cannot be changed")
+                                    .visible_alias("submit")
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone())
+                                    .arg(
+                                        Arg::new(PARAMETER_MESSAGE)
+                                            .long(PARAMETER_MESSAGE)
+                                            .required(true)
+                                            .help("Change log message")
+                                    ),
+                            ),
+                    )
+                    .subcommand(
+                        Command::new(COMMAND_FILE)
+                            .about("Files of an asset")
+                            .subcommand_required(true)
+                            .subcommand(
+                                Command::new(COMMAND_LIST)
+                                    .about("Lists the files of an asset with their sizes, checksums and datasets")
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone())
+                                    .arg(
+                                        Arg::new(PARAMETER_DATASET)
+                                            .long(PARAMETER_DATASET)
+                                            .required(false)
+                                            .help("Optional: Only the files of this dataset (name or ID)")
+                                    ),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_GET)
+                                    .about("Returns the details of a file, including its tags and metadata")
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone())
+                                    .arg(dataset_parameter.clone())
+                                    .arg(
+                                        Arg::new(PARAMETER_FILE)
+                                            .long(PARAMETER_FILE)
+                                            .required(true)
+                                            .help("File path in the dataset")
+                                    ),
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_UPDATE)
+                                    .about("Updates the description, tags and metadata of a file")
+                                    .arg(asset_id_parameter.clone())
+                                    .arg(asset_version_parameter.clone())
+                                    .arg(dataset_parameter.clone())
+                                    .arg(
+                                        Arg::new(PARAMETER_FILE)
+                                            .long(PARAMETER_FILE)
+                                            .required(true)
+                                            .help("File path in the dataset")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_DESCRIPTION)
+                                            .long(PARAMETER_DESCRIPTION)
+                                            .required(false)
+                                            .help("Optional: New file description"),
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_ADD_TAG)
+                                            .long(PARAMETER_ADD_TAG)
+                                            .required(false)
+                                            .action(ArgAction::Append)
+                                            .help("Optional: Tag to be added. Can be repeated")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_REMOVE_TAG)
+                                            .long(PARAMETER_REMOVE_TAG)
+                                            .required(false)
+                                            .action(ArgAction::Append)
+                                            .help("Optional: Tag to be removed. Can be repeated")
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_METADATA_KEY)
+                                            .long(PARAMETER_METADATA_KEY)
+                                            .required(false)
+                                            .action(ArgAction::Append)
+                                            .help("Optional: Metadata value in the form key=value. Can be repeated")
+                                    ),
+                            ),
+                    )
+            )
+            .subcommand(
+                // Organization metadata field definitions
+                Command::new(COMMAND_METADATA)
+                    .about("Working with metadata field definitions")
+                    .subcommand_required(true)
+                    .subcommand(
+                        Command::new(COMMAND_FIELD)
+                            .about("Metadata field definitions in the organization")
+                            .subcommand_required(true)
+                            .subcommand(
+                                Command::new(COMMAND_LIST)
+                                    .about("Lists all metadata field definitions")
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_GET)
+                                    .about("Returns a metadata field definition")
+                                    .arg(field_name_parameter.clone())
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_CREATE)
+                                    .about("Creates a new metadata field definition")
+                                    .arg(field_name_parameter.clone())
+                                    .arg(
+                                        Arg::new(PARAMETER_TYPE)
+                                            .long(PARAMETER_TYPE)
+                                            .required(false)
+                                            .default_value("text")
+                                            .help("Field type: text, number, boolean, timestamp, url, user, single-select or multi-select")
+                                    )
+                                    .arg(display_name_parameter.clone())
+                                    .arg(accepted_value_parameter.clone())
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_UPDATE)
+                                    .about("Updates the display name and/or the accepted values of a metadata field definition")
+                                    .arg(field_name_parameter.clone())
+                                    .arg(display_name_parameter.clone())
+                                    .arg(accepted_value_parameter.clone())
+                            )
+                            .subcommand(
+                                Command::new(COMMAND_DELETE)
+                                    .about("Deletes a metadata field definition")
+                                    .arg(field_name_parameter.clone())
+                            )
+                    )
+                    .subcommand(
+                        Command::new(COMMAND_SCHEMA)
+                            .about("Declarative management of metadata field definitions")
+                            .subcommand_required(true)
+                            .subcommand(
+                                Command::new(COMMAND_APPLY)
+                                    .about("Creates or updates the metadata field definitions to match a schema file and reports the drift")
+                                    .arg(
+                                        Arg::new(PARAMETER_SCHEMA)
+                                            .required(true)
+                                            .help("Schema file in YAML or JSON format")
+                                            .value_parser(clap::value_parser!(PathBuf)),
+                                    )
+                                    .arg(
+                                        Arg::new(PARAMETER_DRY_RUN)
+                                            .long(PARAMETER_DRY_RUN)
+                                            .required(false)
+                                            .action(ArgAction::SetTrue)
+                                            .help("If present, only reports the drift without applying any changes")
+                                    )
+                            )
+                    )
+            )
+            .get_matches()
+    }
+
+    /// Parses the command line arguments and executes matching commands.
+    /// Returns Ok(()) if successfule or CliError otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * api - configured API object
+    ///
+    pub async fn execute_command(&self, mut api: Api) -> Result<(), CliError> {
+        match self.prepare_commands().subcommand() {
+            // configuration commands and their parameters
+            Some((COMMAND_CONFIG, sub_matches)) => match sub_matches.subcommand() {
+                Some((COMMAND_PATH, sub_matches)) => match sub_matches.subcommand() {
+                    Some((COMMAND_GET, _)) => {
+                        let path = Configuration::get_default_configuration_file_path()?;
+                        let path = path.into_os_string().into_string().unwrap();
+                        println!("{}", path);
+                    }
+                    _ => unreachable!("Invalid command"),
+                },
+                Some((COMMAND_CLIENT, sub_matches)) => match sub_matches.subcommand() {
+                    Some((COMMAND_SET, sub_matches)) => {
+                        let organization_id = sub_matches
+                            .get_one::<String>(PARAMETER_ORGANIZATION)
+                            .unwrap();
+                        let project_id =
+                            sub_matches.get_one::<String>(PARAMETER_PROJECT_ID).unwrap(); // unwraps here are safe, because the arguments is mandatory and it will caught by Clap before this point
+                        let environment_id = sub_matches
+                            .get_one::<String>(PARAMETER_ENVIRONMENT_ID)
+                            .unwrap();
                         let client_id = sub_matches.get_one::<String>(PARAMETER_CLIENT_ID).unwrap();
                         let client_secret = sub_matches
                             .get_one::<String>(PARAMETER_CLIENT_SECRET)
@@ -385,6 +1402,45 @@ impl Cli {
                     }
                     _ => unreachable!("Invalid command"),
                 },
+                Some((COMMAND_CHECKSUM, sub_matches)) => match sub_matches.subcommand() {
+                    Some((COMMAND_SET, sub_matches)) => {
+                        let algorithm = sub_matches.get_one::<String>(PARAMETER_ALGORITHM).unwrap();
+                        let algorithm: ChecksumAlgorithm = algorithm.as_str().parse()?;
+
+                        let configuration = api.configuration();
+                        let mut configuration = configuration.borrow_mut();
+                        configuration.set_checksum_algorithm(algorithm);
+                        configuration.save_to_default()?;
+                    }
+                    Some((COMMAND_GET, _)) => {
+                        println!("{}", api.configuration().borrow().checksum_algorithm());
+                    }
+                    _ => unreachable!("Invalid command"),
+                },
+                Some((COMMAND_TYPE, sub_matches)) => match sub_matches.subcommand() {
+                    Some((COMMAND_SET, sub_matches)) => {
+                        let extension = sub_matches.get_one::<String>(PARAMETER_EXTENSION).unwrap();
+                        let primary_type = sub_matches.get_one::<String>(PARAMETER_TYPE).unwrap();
+
+                        let configuration = api.configuration();
+                        let mut configuration = configuration.borrow_mut();
+                        configuration.set_primary_type(extension, primary_type.to_owned());
+                        configuration.save_to_default()?;
+                    }
+                    Some((COMMAND_GET, _)) => {
+                        let json = serde_json::to_string(&api.configuration().borrow().primary_types()).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_DELETE, sub_matches)) => {
+                        let extension = sub_matches.get_one::<String>(PARAMETER_EXTENSION).unwrap();
+
+                        let configuration = api.configuration();
+                        let mut configuration = configuration.borrow_mut();
+                        configuration.remove_primary_type(extension);
+                        configuration.save_to_default()?;
+                    }
+                    _ => unreachable!("Invalid command"),
+                },
                 Some((COMMAND_EXPORT, sub_matches)) => {
                     let path = sub_matches.get_one::<PathBuf>(PARAMETER_OUTPUT).unwrap(); // it is save vefause the argument is mandatory
                     api.configuration().borrow().save(path)?;
@@ -401,35 +1457,207 @@ impl Cli {
                     let asset_id: Option<&String> = sub_matches.get_one::<String>(PARAMETER_ASSET_ID);
                     let asset_version: Option<&String> = sub_matches.get_one::<String>(PARAMETER_ASSET_VERSION);
 
-                    let asset_id = match (asset_id, asset_version) {
-                        (Some(asset_id), Some(asset_version)) => {
-                            Some(AssetIdentity::new(asset_id.to_owned(), asset_version.to_owned()))
+                    let asset_id = match (asset_id, asset_version) {
+                        (Some(asset_id), Some(asset_version)) => {
+                            Some(AssetIdentity::new(asset_id.to_owned(), asset_version.to_owned()))
+                        }
+                        _ => None,
+                    };
+
+                    let assets = api.search_asset(asset_id, asset_name.cloned()).await?;
+                    let json = serde_json::to_string(&assets).unwrap();
+                    println!("{}", json);
+                }
+                Some((COMMAND_CREATE, sub_matches)) => {
+                    let name = sub_matches.get_one::<String>(PARAMETER_NAME);
+                    let description = sub_matches.get_one::<String>(PARAMETER_DESCRIPTION);
+                    let primary_type = sub_matches.get_one::<String>(PARAMETER_TYPE).cloned();
+                    let data_file_paths = sub_matches
+                        .get_many::<PathBuf>(PARAMETER_DATA_FILE)
+                        .unwrap();
+                    let data_file_paths: Vec<&PathBuf> =
+                        data_file_paths.into_iter().collect();
+                    let publish = sub_matches.get_flag(PARAMETER_PROJECT_ID);
+                    let sidecar = sub_matches.get_flag(PARAMETER_SIDECAR);
+                    let extract_stats = sub_matches.get_flag(PARAMETER_EXTRACT_STATS);
+                    let validate = !sub_matches.get_flag(PARAMETER_NO_VALIDATE);
+                    let include_references = sub_matches.get_flag(PARAMETER_INCLUDE_REFERENCES);
+                    let checksum_algorithm = match sub_matches.get_one::<String>(PARAMETER_CHECKSUM) {
+                        Some(algorithm) => Some(algorithm.as_str().parse::<ChecksumAlgorithm>()?),
+                        None => None,
+                    };
+                    let tags: Vec<String> = sub_matches
+                        .get_many::<String>(PARAMETER_TAG)
+                        .map(|iter| iter.cloned().collect())
+                        .unwrap_or_default();
+
+                    // values from the metadata file are overriden by the ones provided explicitly
+                    let mut metadata = match sub_matches.get_one::<PathBuf>(PARAMETER_METADATA_FILE) {
+                        Some(path) => Api::read_metadata_file(path, None, DEFAULT_METADATA_KEY_SEPARATOR)?,
+                        None => HashMap::new(),
+                    };
+                    for entry in sub_matches.get_many::<String>(PARAMETER_METADATA_KEY).unwrap_or_default() {
+                        match entry.split_once('=') {
+                            Some((key, value)) => {
+                                metadata.insert(key.trim().to_owned(), serde_json::Value::String(value.to_owned()));
+                            }
+                            None => return Err(CliError::InvalidArgument(entry.to_owned())),
+                        }
+                    }
+
+                    let options = AssetCreateOptions {
+                        primary_type,
+                        tags,
+                        metadata,
+                        publish,
+                        checksum_algorithm,
+                        sidecar,
+                        extract_stats,
+                        validate,
+                        include_references,
+                        dataset: sub_matches.get_one::<String>(PARAMETER_DATASET).cloned(),
+                    };
+
+                    let result = api
+                        .create_asset(
+                            name.cloned(),
+                            description.to_owned().map(|s| s.to_owned()),
+                            data_file_paths,
+                            options,
+                        )
+                        .await?;
+                    let json = serde_json::to_string(&result).unwrap();
+                    println!("{}", json);
+                }
+                Some((COMMAND_VALIDATE, sub_matches)) => {
+                    let data_file_paths: Vec<&PathBuf> = sub_matches
+                        .get_many::<PathBuf>(PARAMETER_DATA_FILE)
+                        .unwrap()
+                        .collect();
+                    let include_references = sub_matches.get_flag(PARAMETER_INCLUDE_REFERENCES);
+
+                    let report = api.validate_files(&data_file_paths, include_references);
+                    let json = serde_json::to_string(&report).unwrap();
+                    println!("{}", json);
+                    if !report.is_valid() {
+                        let errors: Vec<String> = report.errors().map(|e| e.to_string()).collect();
+                        return Err(ApiError::ValidationFailed(errors.join("\n")).into());
+                    }
+                }
+                Some((COMMAND_UPDATE, sub_matches)) => {
+                    let version = sub_matches
+                        .get_one::<String>(PARAMETER_ASSET_VERSION)
+                        .unwrap();
+                    let identities: Vec<AssetIdentity> = match sub_matches.get_many::<String>(PARAMETER_ASSET_ID) {
+                        Some(ids) => ids
+                            .map(|id| AssetIdentity::new(id.to_owned(), version.to_owned()))
+                            .collect(),
+                        None => {
+                            let asset_name = sub_matches.get_one::<String>(PARAMETER_ASSET_NAME).cloned();
+                            api.search_asset(None, asset_name)
+                                .await?
+                                .into_iter()
+                                .map(|asset| asset.identity())
+                                .collect()
+                        }
+                    };
+
+                    let options = AssetUpdateOptions {
+                        name: sub_matches.get_one::<String>(PARAMETER_NAME).cloned(),
+                        description: sub_matches.get_one::<String>(PARAMETER_DESCRIPTION).cloned(),
+                        add_tags: sub_matches
+                            .get_many::<String>(PARAMETER_ADD_TAG)
+                            .map(|iter| iter.cloned().collect())
+                            .unwrap_or_default(),
+                        remove_tags: sub_matches
+                            .get_many::<String>(PARAMETER_REMOVE_TAG)
+                            .map(|iter| iter.cloned().collect())
+                            .unwrap_or_default(),
+                        primary_type: sub_matches.get_one::<String>(PARAMETER_TYPE).cloned(),
+                    };
+
+                    let result = api.update_assets(identities, &options).await?;
+                    let json = serde_json::to_string(&result).unwrap();
+                    println!("{}", json);
+                }
+                Some((COMMAND_UPLOAD, sub_matches)) => {
+                    let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                    let version = sub_matches
+                        .get_one::<String>(PARAMETER_ASSET_VERSION)
+                        .unwrap();
+                    let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                    let dataset = sub_matches.get_one::<String>(PARAMETER_DATASET).unwrap();
+                    let data_file_paths: Vec<&PathBuf> = sub_matches
+                        .get_many::<PathBuf>(PARAMETER_DATA_FILE)
+                        .unwrap()
+                        .collect();
+                    let skip_unchanged = sub_matches.get_flag(PARAMETER_SKIP_UNCHANGED);
+                    let checksum_algorithm = match sub_matches.get_one::<String>(PARAMETER_CHECKSUM) {
+                        Some(algorithm) => Some(algorithm.as_str().parse::<ChecksumAlgorithm>()?),
+                        None => None,
+                    };
+
+                    let result = api
+                        .upload_asset_files(&identity, dataset, data_file_paths, skip_unchanged, checksum_algorithm)
+                        .await?;
+                    let json = serde_json::to_string(&result).unwrap();
+                    println!("{}", json);
+                }
+                Some((COMMAND_DUPLICATES, _)) => {
+                    let result = api.find_duplicates().await?;
+                    let json = serde_json::to_string(&result).unwrap();
+                    println!("{}", json);
+                }
+                Some((COMMAND_DIFF, sub_matches)) => {
+                    let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+
+                    if let Some(directory) = sub_matches.get_one::<PathBuf>(PARAMETER_LOCAL) {
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                        let dataset = sub_matches.get_one::<String>(PARAMETER_DATASET).map(|d| d.as_str());
+
+                        if sub_matches.get_flag(PARAMETER_APPLY) {
+                            let result = api.apply_local_diff(&identity, directory, dataset).await?;
+                            let json = serde_json::to_string(&result).unwrap();
+                            println!("{}", json);
+                        } else {
+                            let diff = api.diff_local_files(&identity, directory, dataset).await?;
+                            match sub_matches.get_one::<String>(PARAMETER_FORMAT).map(|f| f.as_str()) {
+                                Some("json") => {
+                                    let json = serde_json::to_string(&diff).unwrap();
+                                    println!("{}", json);
+                                }
+                                _ => print_diff(&diff),
+                            }
                         }
-                        _ => None,
-                    };
+                    } else {
+                        let from = sub_matches.get_one::<String>(PARAMETER_FROM).unwrap();
+                        let to = sub_matches.get_one::<String>(PARAMETER_TO).unwrap();
 
-                    let assets = api.search_asset(asset_id, asset_name.map(|n| n.clone())).await?;
-                    let json = serde_json::to_string(&assets).unwrap();
-                    println!("{}", json);
+                        let diff = api.diff_asset_versions(id, from, to).await?;
+                        match sub_matches.get_one::<String>(PARAMETER_FORMAT).map(|f| f.as_str()) {
+                            Some("json") => {
+                                let json = serde_json::to_string(&diff).unwrap();
+                                println!("{}", json);
+                            }
+                            _ => print_diff(&diff),
+                        }
+                    }
                 }
-                Some((COMMAND_CREATE, sub_matches)) => {
-                    let name = sub_matches.get_one::<String>(PARAMETER_NAME).unwrap();
-                    let description = sub_matches.get_one::<String>(PARAMETER_DESCRIPTION);
-                    let data_file_paths = sub_matches
-                        .get_many::<PathBuf>(PARAMETER_DATA_FILE)
+                Some((COMMAND_VERIFY, sub_matches)) => {
+                    let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                    let version = sub_matches
+                        .get_one::<String>(PARAMETER_ASSET_VERSION)
                         .unwrap();
-                    let data_file_paths: Vec<&PathBuf> =
-                        data_file_paths.into_iter().map(|p| p.into()).collect();
-                    let publish = sub_matches.get_flag(PARAMETER_PROJECT_ID);
+                    let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                    let paths: Vec<&PathBuf> = sub_matches
+                        .get_many::<PathBuf>(PARAMETER_DATA_FILE)
+                        .unwrap()
+                        .collect();
 
-                    let result = api
-                        .create_asset(
-                            name.to_owned(),
-                            description.to_owned().map(|s| s.to_owned()),
-                            data_file_paths,
-                            publish,
-                        )
-                        .await?;
+                    let result = api.verify_asset(&identity, paths).await?;
                     let json = serde_json::to_string(&result).unwrap();
                     println!("{}", json);
                 }
@@ -457,23 +1685,109 @@ impl Cli {
                     let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
                     let output_directory = sub_matches.get_one::<PathBuf>(PARAMETER_DOWNLOAD_DIR);
 
-                    let _ = api.download_asset(&identity, output_directory).await?;
+                    api.download_asset(&identity, output_directory).await?;
                 }
                 Some((COMMAND_STATUS, sub_matches)) => match sub_matches.subcommand() {
                     Some((COMMAND_SET, sub_matches)) => {
-                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
                         let version = sub_matches
                             .get_one::<String>(PARAMETER_ASSET_VERSION)
                             .unwrap();
-                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
-                        let status = sub_matches.get_one::<String>(PARAMETER_STATUS).unwrap();
+                        let mut identities: Vec<AssetIdentity> = sub_matches
+                            .get_many::<String>(PARAMETER_ASSET_ID)
+                            .unwrap_or_default()
+                            .map(|id| AssetIdentity::new(id.to_owned(), version.to_owned()))
+                            .collect();
+                        if let Some(path) = sub_matches.get_one::<PathBuf>(PARAMETER_IDS_FILE) {
+                            let reader = BufReader::new(File::open(path).map_err(crate::api::ApiError::from)?);
+                            identities.extend(Api::read_asset_identities(reader, version)?);
+                        }
+                        if sub_matches.get_flag(PARAMETER_STDIN) {
+                            identities.extend(Api::read_asset_identities(std::io::stdin().lock(), version)?);
+                        }
+                        let status = sub_matches.get_one::<String>(PARAMETER_TO).unwrap();
                         let status: AssetStatus = status.as_str().parse()?;
+                        let from: Option<AssetStatus> = match sub_matches.get_one::<String>(PARAMETER_FROM) {
+                            Some(from) => Some(from.parse()?),
+                            None => None,
+                        };
+                        let selection = AssetSelection {
+                            identities,
+                            asset_name: sub_matches.get_one::<String>(PARAMETER_ASSET_NAME).cloned(),
+                            status: from,
+                            has_thumbnail: sub_matches.get_flag(PARAMETER_HAS_THUMBNAIL),
+                            metadata_keys: sub_matches
+                                .get_many::<String>(PARAMETER_METADATA_KEY)
+                                .unwrap_or_default()
+                                .cloned()
+                                .collect(),
+                        };
+                        let dry_run = sub_matches.get_flag(PARAMETER_DRY_RUN);
+                        let single = selection.identities.len() == 1
+                            && sub_matches.get_one::<String>(PARAMETER_ASSET_ID).is_some()
+                            && !sub_matches.contains_id(PARAMETER_FROM)
+                            && !sub_matches.contains_id(PARAMETER_ASSET_NAME)
+                            && !selection.has_thumbnail
+                            && selection.metadata_keys.is_empty()
+                            && !dry_run;
 
-                        let _ = api.set_asset_status(&identity, &status).await?;
+                        if single {
+                            let transition = api.transition_asset_status(&selection.identities[0], &status).await?;
+                            let json = serde_json::to_string(&transition).unwrap();
+                            println!("{}", json);
+                        } else {
+                            let results = api.transition_assets_status(&selection, &status, dry_run).await?;
+                            match sub_matches.get_one::<String>(PARAMETER_FORMAT).map(|f| f.as_str()) {
+                                Some("json") => {
+                                    let json = serde_json::to_string(&results).unwrap();
+                                    println!("{}", json);
+                                }
+                                _ => {
+                                    let rows = results
+                                        .iter()
+                                        .map(|r| {
+                                            let path = r.path.iter().map(|s| s.name()).collect::<Vec<&str>>().join(" -> ");
+                                            let result = match &r.error {
+                                                Some(error) => format!("{:?}: {}", r.status, error),
+                                                None => format!("{:?}", r.status),
+                                            };
+                                            vec![r.asset.id(), r.asset.version(), r.name.to_owned(), r.from.name().to_owned(), path, result]
+                                        })
+                                        .collect();
+                                    print_table(&["ASSET ID", "VERSION", "NAME", "FROM", "PATH", "RESULT"], rows);
+                                }
+                            }
+                        }
                     }
                     _ => unreachable!("Invalid subcommand for 'asset status"),
                 },
                 Some((COMMAND_METADATA, sub_matches)) => match sub_matches.subcommand() {
+                    Some((COMMAND_GET, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+
+                        let metadata = api.get_asset_metadata(&identity).await?;
+                        let json = serde_json::to_string(&metadata).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_EXPORT, sub_matches)) => {
+                        let output = sub_matches.get_one::<PathBuf>(PARAMETER_OUTPUT).unwrap();
+                        let asset_name: Option<&String> = sub_matches.get_one::<String>(PARAMETER_ASSET_NAME);
+                        let asset_id: Option<&String> = sub_matches.get_one::<String>(PARAMETER_ASSET_ID);
+                        let asset_version: Option<&String> = sub_matches.get_one::<String>(PARAMETER_ASSET_VERSION);
+
+                        let asset_id = match (asset_id, asset_version) {
+                            (Some(asset_id), Some(asset_version)) => {
+                                Some(AssetIdentity::new(asset_id.to_owned(), asset_version.to_owned()))
+                            }
+                            _ => None,
+                        };
+
+                        let count = api.export_asset_metadata(asset_id, asset_name.cloned(), output).await?;
+                        log::trace!("Exported metadata for {} assets", count);
+                    }
                     Some((COMMAND_UPLOAD, sub_matches)) => {
                         let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
                         let version = sub_matches
@@ -483,8 +1797,28 @@ impl Cli {
 
                         let data_file_path =
                             sub_matches.get_one::<PathBuf>(PARAMETER_DATA_FILE).unwrap();
+                        let format: Option<MetadataFormat> = match sub_matches.get_one::<String>(PARAMETER_FORMAT) {
+                            Some(format) => Some(format.as_str().parse()?),
+                            None => None,
+                        };
+                        let separator = sub_matches.get_one::<String>(PARAMETER_SEPARATOR).unwrap();
+                        let mode = sub_matches.get_one::<String>(PARAMETER_MODE).unwrap();
+                        let mode: MetadataMode = mode.as_str().parse()?;
+                        let dry_run = sub_matches.get_flag(PARAMETER_DRY_RUN);
+
+                        let changes = api.upload_asset_metadata(&identity, data_file_path, format, separator, mode, dry_run).await?;
+                        let json = serde_json::to_string(&changes).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_IMPORT, sub_matches)) => {
+                        let data_file_path = sub_matches.get_one::<PathBuf>(PARAMETER_DATA_FILE).unwrap();
+                        let mode = sub_matches.get_one::<String>(PARAMETER_MODE).unwrap();
+                        let mode: MetadataMode = mode.as_str().parse()?;
+                        let dry_run = sub_matches.get_flag(PARAMETER_DRY_RUN);
 
-                        let _ = api.upload_asset_metadata(&identity, data_file_path).await?;
+                        let results = api.import_asset_metadata(data_file_path, mode, dry_run).await?;
+                        let json = serde_json::to_string(&results).unwrap();
+                        println!("{}", json);
                     }
                     Some((COMMAND_DELETE, sub_matches)) => {
                         let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
@@ -495,7 +1829,7 @@ impl Cli {
                         let keys = sub_matches.get_many::<String>(PARAMETER_METADATA_KEY).map(|iter| iter.cloned().collect::<Vec<String>>()).unwrap();
 
 
-                        let _ = api.delete_asset_metadata(&identity, &keys).await?;
+                        api.delete_asset_metadata(&identity, &keys).await?;
                     }
                     _ => unreachable!("Invalid subsommand for 'asset metadata'"), // this will never be reached because the command is validated first
                 },
@@ -510,15 +1844,530 @@ impl Cli {
                         _ => None,
                     };
 
-                    let assets = api.generate_asset_thumbnail(asset_id).await?;
-                    let json = serde_json::to_string(&assets).unwrap();
+                    let wait = match sub_matches.get_flag(PARAMETER_NO_WAIT) {
+                        true => None,
+                        false => Some(
+                            sub_matches
+                                .get_one::<u64>(PARAMETER_TIMEOUT)
+                                .map(|t| Duration::from_secs(*t))
+                                .unwrap_or(DEFAULT_TRANSFORMATION_TIMEOUT),
+                        ),
+                    };
+
+                    let results = api.generate_asset_thumbnail(asset_id, wait).await?;
+                    let json = serde_json::to_string(&results).unwrap();
+                    println!("{}", json);
+                }
+                Some((COMMAND_PREVIEW, sub_matches)) => match sub_matches.subcommand() {
+                    Some((COMMAND_RENDER, sub_matches)) => {
+                        let data_file = sub_matches.get_one::<PathBuf>(PARAMETER_DATA_FILE);
+                        let output = sub_matches.get_one::<PathBuf>(PARAMETER_OUTPUT);
+                        let size = sub_matches.get_one::<u32>(PARAMETER_SIZE).copied().unwrap_or(DEFAULT_PREVIEW_SIZE);
+
+                        let result = match sub_matches.get_flag(PARAMETER_NO_UPLOAD) {
+                            true => {
+                                let data_file = data_file.unwrap();
+                                let output = output.unwrap();
+                                let triangles = Api::render_preview(data_file, size, output)?;
+                                PreviewRenderResult {
+                                    asset: None,
+                                    source: data_file.to_string_lossy().to_string(),
+                                    triangles,
+                                    output: Some(output.to_owned()),
+                                    dataset_id: None,
+                                    preview_file: None,
+                                }
+                            }
+                            false => {
+                                let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                                let version = sub_matches
+                                    .get_one::<String>(PARAMETER_ASSET_VERSION)
+                                    .unwrap();
+                                let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+
+                                api.render_asset_preview(&identity, data_file.map(|f| f.as_path()), size, output.map(|o| o.as_path()))
+                                    .await?
+                            }
+                        };
+                        let json = serde_json::to_string(&result).unwrap();
+                        println!("{}", json);
+                    }
+                    _ => unreachable!("Invalid subcommand for 'asset preview'"),
+                },
+                Some((COMMAND_ANALYZE, sub_matches)) => {
+                    let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                    let version = sub_matches.get_one::<String>(PARAMETER_ASSET_VERSION).unwrap();
+                    let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                    let data_files: Vec<&Path> = sub_matches
+                        .get_many::<PathBuf>(PARAMETER_DATA_FILE)
+                        .map(|iter| iter.map(|f| f.as_path()).collect())
+                        .unwrap_or_default();
+                    let dry_run = sub_matches.get_flag(PARAMETER_DRY_RUN);
+
+                    let result = api.analyze_asset(&identity, data_files, dry_run).await?;
+                    let json = serde_json::to_string(&result).unwrap();
                     println!("{}", json);
                 }
+                Some((COMMAND_TRANSFORM, sub_matches)) => match sub_matches.subcommand() {
+                    Some((COMMAND_START, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                        let dataset = sub_matches.get_one::<String>(PARAMETER_DATASET).unwrap();
+                        let workflow = sub_matches.get_one::<String>(PARAMETER_WORKFLOW).unwrap();
+                        let files: Vec<String> = sub_matches
+                            .get_many::<String>(PARAMETER_FILE)
+                            .unwrap_or_default()
+                            .cloned()
+                            .collect();
+                        let mut parameters: HashMap<String, serde_json::Value> = HashMap::new();
+                        for entry in sub_matches.get_many::<String>(PARAMETER_PARAM).unwrap_or_default() {
+                            match entry.split_once('=') {
+                                Some((key, value)) => {
+                                    // numbers, booleans and JSON values are passed as such, anything else as a string
+                                    let value = serde_json::from_str(value)
+                                        .unwrap_or_else(|_| serde_json::Value::String(value.to_owned()));
+                                    parameters.insert(key.trim().to_owned(), value);
+                                }
+                                None => return Err(CliError::InvalidArgument(entry.to_owned())),
+                            }
+                        }
+
+                        let mut transformation = api
+                            .start_transformation(&identity, dataset, workflow, files, parameters)
+                            .await?;
+                        if sub_matches.get_flag(PARAMETER_WAIT) {
+                            let timeout = sub_matches
+                                .get_one::<u64>(PARAMETER_TIMEOUT)
+                                .map(|t| Duration::from_secs(*t))
+                                .unwrap_or(DEFAULT_TRANSFORMATION_TIMEOUT);
+                            transformation = api
+                                .wait_transformation(&identity, dataset, &transformation.id(), timeout)
+                                .await?;
+                        }
+                        let json = serde_json::to_string(&transformation).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_LIST, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                        let dataset = sub_matches.get_one::<String>(PARAMETER_DATASET);
+
+                        let transformations = api
+                            .list_transformations(&identity, dataset.map(|d| d.as_str()))
+                            .await?;
+                        let json = serde_json::to_string(&transformations).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_STATUS, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                        let dataset = sub_matches.get_one::<String>(PARAMETER_DATASET).unwrap();
+                        let transformation_id = sub_matches
+                            .get_one::<String>(PARAMETER_TRANSFORMATION_ID)
+                            .unwrap();
+
+                        let transformation = api.get_transformation(&identity, dataset, transformation_id).await?;
+                        let json = serde_json::to_string(&transformation).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_CANCEL, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                        let dataset = sub_matches.get_one::<String>(PARAMETER_DATASET).unwrap();
+                        let transformation_id = sub_matches
+                            .get_one::<String>(PARAMETER_TRANSFORMATION_ID)
+                            .unwrap();
+
+                        let transformation = api.cancel_transformation(&identity, dataset, transformation_id).await?;
+                        let json = serde_json::to_string(&transformation).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_WAIT, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                        let dataset = sub_matches.get_one::<String>(PARAMETER_DATASET).unwrap();
+                        let transformation_id = sub_matches
+                            .get_one::<String>(PARAMETER_TRANSFORMATION_ID)
+                            .unwrap();
+                        let timeout = sub_matches
+                            .get_one::<u64>(PARAMETER_TIMEOUT)
+                            .map(|t| Duration::from_secs(*t))
+                            .unwrap_or(DEFAULT_TRANSFORMATION_TIMEOUT);
+
+                        let transformation = api
+                            .wait_transformation(&identity, dataset, transformation_id, timeout)
+                            .await?;
+                        let json = serde_json::to_string(&transformation).unwrap();
+                        println!("{}", json);
+                    }
+                    _ => unreachable!("Invalid subcommand for 'asset transform'"),
+                },
+                Some((COMMAND_DATASET, sub_matches)) => match sub_matches.subcommand() {
+                    Some((COMMAND_LIST, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+
+                        let datasets = api.list_datasets(&identity).await?;
+                        let json = serde_json::to_string(&datasets).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_GET, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                        let dataset = sub_matches.get_one::<String>(PARAMETER_DATASET).unwrap();
+
+                        let dataset = api.get_dataset(&identity, dataset).await?;
+                        let json = serde_json::to_string(&dataset).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_CREATE, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                        let name = sub_matches.get_one::<String>(PARAMETER_NAME).unwrap();
+                        let tags: Vec<String> = sub_matches
+                            .get_many::<String>(PARAMETER_TAG)
+                            .map(|iter| iter.cloned().collect())
+                            .unwrap_or_default();
+
+                        let mut dataset = Dataset::new(
+                            String::new(),
+                            name.to_owned(),
+                            sub_matches.get_one::<String>(PARAMETER_TYPE).cloned(),
+                        );
+                        dataset.set_description(sub_matches.get_one::<String>(PARAMETER_DESCRIPTION).cloned());
+                        if !tags.is_empty() {
+                            dataset.set_tags(Some(tags));
+                        }
+
+                        let dataset = api
+                            .create_dataset(&identity, &dataset, sub_matches.get_flag(PARAMETER_PREVIEW))
+                            .await?;
+                        let json = serde_json::to_string(&dataset).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_UPDATE, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                        let dataset = sub_matches.get_one::<String>(PARAMETER_DATASET).unwrap();
+
+                        let options = DatasetUpdateOptions {
+                            name: sub_matches.get_one::<String>(PARAMETER_NAME).cloned(),
+                            description: sub_matches.get_one::<String>(PARAMETER_DESCRIPTION).cloned(),
+                            add_tags: sub_matches
+                                .get_many::<String>(PARAMETER_ADD_TAG)
+                                .map(|iter| iter.cloned().collect())
+                                .unwrap_or_default(),
+                            remove_tags: sub_matches
+                                .get_many::<String>(PARAMETER_REMOVE_TAG)
+                                .map(|iter| iter.cloned().collect())
+                                .unwrap_or_default(),
+                            primary_type: sub_matches.get_one::<String>(PARAMETER_TYPE).cloned(),
+                        };
+
+                        let result = api
+                            .update_dataset(&identity, dataset, &options, sub_matches.get_flag(PARAMETER_PREVIEW))
+                            .await?;
+                        let json = serde_json::to_string(&result).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_DELETE, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                        let dataset = sub_matches.get_one::<String>(PARAMETER_DATASET).unwrap();
+
+                        api.delete_dataset(&identity, dataset).await?;
+                    }
+                    _ => unreachable!("Invalid subcommand for 'asset dataset'"),
+                },
+                Some((COMMAND_VERSION, sub_matches)) => match sub_matches.subcommand() {
+                    Some((COMMAND_LIST, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+
+                        let versions = api.list_asset_versions(id).await?;
+                        let json = serde_json::to_string(&versions).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_LATEST, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), LATEST_VERSION.to_string());
+
+                        let version = api.get_asset_version(&identity).await?;
+                        let json = serde_json::to_string(&version).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_GET, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+
+                        let version = api.get_asset_version(&identity).await?;
+                        let json = serde_json::to_string(&version).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_CREATE, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                        let keep_files = !sub_matches.get_flag(PARAMETER_NO_FILES);
+
+                        let version = api.create_asset_version(&identity, keep_files).await?;
+                        let json = serde_json::to_string(&version).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_FREEZE, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                        let message = sub_matches.get_one::<String>(PARAMETER_MESSAGE).unwrap();
+
+                        let version = api.freeze_asset_version(&identity, message).await?;
+                        let json = serde_json::to_string(&version).unwrap();
+                        println!("{}", json);
+                    }
+                    _ => unreachable!("Invalid subcommand for 'asset version'"),
+                },
+                Some((COMMAND_FILE, sub_matches)) => match sub_matches.subcommand() {
+                    Some((COMMAND_LIST, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                        let dataset = sub_matches.get_one::<String>(PARAMETER_DATASET);
+
+                        let files = api
+                            .list_files(&identity, dataset.map(|d| d.as_str()))
+                            .await?;
+                        let json = serde_json::to_string(&files).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_GET, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                        let dataset = sub_matches.get_one::<String>(PARAMETER_DATASET).unwrap();
+                        let path = sub_matches.get_one::<String>(PARAMETER_FILE).unwrap();
+
+                        let file = api.get_file(&identity, dataset, path).await?;
+                        let json = serde_json::to_string(&file).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_UPDATE, sub_matches)) => {
+                        let id = sub_matches.get_one::<String>(PARAMETER_ASSET_ID).unwrap();
+                        let version = sub_matches
+                            .get_one::<String>(PARAMETER_ASSET_VERSION)
+                            .unwrap();
+                        let identity = AssetIdentity::new(id.to_owned(), version.to_owned());
+                        let dataset = sub_matches.get_one::<String>(PARAMETER_DATASET).unwrap();
+                        let path = sub_matches.get_one::<String>(PARAMETER_FILE).unwrap();
+
+                        let mut metadata: HashMap<String, serde_json::Value> = HashMap::new();
+                        for entry in sub_matches.get_many::<String>(PARAMETER_METADATA_KEY).unwrap_or_default() {
+                            match entry.split_once('=') {
+                                Some((key, value)) => {
+                                    metadata.insert(key.trim().to_owned(), serde_json::Value::String(value.to_owned()));
+                                }
+                                None => return Err(CliError::InvalidArgument(entry.to_owned())),
+                            }
+                        }
+                        let options = AssetFileUpdateOptions {
+                            description: sub_matches.get_one::<String>(PARAMETER_DESCRIPTION).cloned(),
+                            add_tags: sub_matches
+                                .get_many::<String>(PARAMETER_ADD_TAG)
+                                .map(|iter| iter.cloned().collect())
+                                .unwrap_or_default(),
+                            remove_tags: sub_matches
+                                .get_many::<String>(PARAMETER_REMOVE_TAG)
+                                .map(|iter| iter.cloned().collect())
+                                .unwrap_or_default(),
+                            metadata,
+                        };
+
+                        let result = api.update_file(&identity, dataset, path, &options).await?;
+                        let json = serde_json::to_string(&result).unwrap();
+                        println!("{}", json);
+                    }
+                    _ => unreachable!("Invalid subcommand for 'asset file'"),
+                },
                 _ => unreachable!("Invalid subsommand for 'asset'"),
             },
+            Some((COMMAND_METADATA, sub_matches)) => match sub_matches.subcommand() {
+                Some((COMMAND_FIELD, sub_matches)) => match sub_matches.subcommand() {
+                    Some((COMMAND_LIST, _)) => {
+                        let definitions = api.list_metadata_definitions().await?;
+                        let json = serde_json::to_string(&definitions).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_GET, sub_matches)) => {
+                        let name = sub_matches.get_one::<String>(PARAMETER_NAME).unwrap();
+
+                        let definition = api.get_metadata_definition(name).await?;
+                        let json = serde_json::to_string(&definition).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_CREATE, sub_matches)) => {
+                        let name = sub_matches.get_one::<String>(PARAMETER_NAME).unwrap();
+                        let field_type = sub_matches.get_one::<String>(PARAMETER_TYPE).unwrap();
+                        let field_type: MetadataFieldType = field_type.as_str().parse()?;
+                        let display_name = sub_matches
+                            .get_one::<String>(PARAMETER_DISPLAY_NAME)
+                            .unwrap_or(name);
+                        let accepted_values: Option<Vec<String>> = sub_matches
+                            .get_many::<String>(PARAMETER_ACCEPTED_VALUE)
+                            .map(|iter| iter.cloned().collect());
+
+                        let definition = MetadataDefinition::new(name.to_owned(), display_name.to_owned(), field_type, accepted_values);
+                        api.create_metadata_definition(&definition).await?;
+                        let json = serde_json::to_string(&definition).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_UPDATE, sub_matches)) => {
+                        let name = sub_matches.get_one::<String>(PARAMETER_NAME).unwrap();
+                        let changes = MetadataDefinitionUpdate {
+                            display_name: sub_matches.get_one::<String>(PARAMETER_DISPLAY_NAME).cloned(),
+                            accepted_values: sub_matches
+                                .get_many::<String>(PARAMETER_ACCEPTED_VALUE)
+                                .map(|iter| iter.cloned().collect()),
+                        };
+
+                        let definition = api.update_metadata_definition(name, &changes).await?;
+                        let json = serde_json::to_string(&definition).unwrap();
+                        println!("{}", json);
+                    }
+                    Some((COMMAND_DELETE, sub_matches)) => {
+                        let name = sub_matches.get_one::<String>(PARAMETER_NAME).unwrap();
+
+                        api.delete_metadata_definition(name).await?;
+                    }
+                    _ => unreachable!("Invalid subcommand for 'metadata field'"),
+                },
+                Some((COMMAND_SCHEMA, sub_matches)) => match sub_matches.subcommand() {
+                    Some((COMMAND_APPLY, sub_matches)) => {
+                        let path = sub_matches.get_one::<PathBuf>(PARAMETER_SCHEMA).unwrap();
+                        let dry_run = sub_matches.get_flag(PARAMETER_DRY_RUN);
+
+                        let drift = api.apply_metadata_schema(path, dry_run).await?;
+                        let json = serde_json::to_string(&drift).unwrap();
+                        println!("{}", json);
+                    }
+                    _ => unreachable!("Invalid subcommand for 'metadata schema'"),
+                },
+                _ => unreachable!("Invalid subcommand for 'metadata'"),
+            },
             _ => unreachable!("Invalid command"),
         }
 
         Ok(())
     }
 }
+
+/// Prints the differences between two asset versions as a table.
+fn print_diff(diff: &AssetDiff) {
+    let value = |value: &Option<serde_json::Value>| match value {
+        Some(serde_json::Value::String(s)) => s.to_owned(),
+        Some(value) => value.to_string(),
+        None => String::new(),
+    };
+    let change = |before: &Option<serde_json::Value>, after: &Option<serde_json::Value>| match (before, after) {
+        (None, _) => "added",
+        (_, None) => "removed",
+        _ => "changed",
+    };
+    let file = |size: Option<u64>, checksum: &Option<String>| {
+        [size.map(|s| s.to_string()), checksum.to_owned()].into_iter().flatten().collect::<Vec<String>>().join(" ")
+    };
+
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    for c in &diff.fields {
+        rows.push(vec!["field".to_string(), c.key.to_owned(), change(&c.before, &c.after).to_string(), value(&c.before), value(&c.after)]);
+    }
+    for c in &diff.metadata {
+        rows.push(vec!["metadata".to_string(), c.key.to_owned(), change(&c.before, &c.after).to_string(), value(&c.before), value(&c.after)]);
+    }
+    for d in &diff.datasets_added {
+        rows.push(vec!["dataset".to_string(), d.to_owned(), "added".to_string(), String::new(), String::new()]);
+    }
+    for d in &diff.datasets_removed {
+        rows.push(vec!["dataset".to_string(), d.to_owned(), "removed".to_string(), String::new(), String::new()]);
+    }
+    for f in &diff.files {
+        rows.push(vec![
+            "file".to_string(),
+            format!("{}/{}", f.dataset, f.path),
+            f.status.to_string(),
+            file(f.size_before, &f.checksum_before),
+            file(f.size_after, &f.checksum_after),
+        ]);
+    }
+
+    println!("Asset {}: version {} -> {}", diff.asset_id, diff.from, diff.to);
+    if rows.is_empty() {
+        println!("No differences");
+    } else {
+        print_table(&["KIND", "NAME", "CHANGE", "BEFORE", "AFTER"], rows);
+    }
+}
+
+/// Prints the rows as a plain text table with left-aligned columns.
+fn print_table(header: &[&str], rows: Vec<Vec<String>>) {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in &rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: Vec<String>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join("  ")
+            .trim_end()
+            .to_owned()
+    };
+
+    println!("{}", format_row(header.iter().map(|h| h.to_string()).collect()));
+    for row in rows {
+        println!("{}", format_row(row));
+    }
+}
@@ -2,8 +2,13 @@
 //!
 //! Contains methods to invoke REST API endpoints.
 //! It is used by the Api struct.
-use crate::model::{
-    Asset, AssetIdentity, AssetStatus, Dataset, MetadataDefinition, ThumbnailGenerationRequest,
+use crate::{
+    checksum::ChecksumAlgorithm,
+    model::{
+        Asset, AssetFile, AssetFileUpdate, AssetIdentity, AssetStatus, AssetType, AssetUpdate,
+        AssetVersion, Dataset, DatasetUpdate, MetadataDefinition, MetadataDefinitionUpdate,
+        Transformation, TransformationRequest,
+    },
 };
 use base64::{engine::general_purpose, Engine};
 use dirs;
@@ -12,9 +17,10 @@ use reqwest::{Body, Client as HttpClient, StatusCode};
 use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
-    fs::File,
+    fs::{self, File},
     io::Write,
     path::{Path, PathBuf},
+    sync::Mutex,
     time::Duration,
 };
 use strfmt::strfmt;
@@ -48,12 +54,16 @@ pub enum ClientError {
     Unsupported(String),
     #[error("no source dataset")]
     NoSourceDataset,
+    #[error("asset {0} has no versions")]
+    NoAssetVersion(String),
     #[error("no preview dataset")]
     NoPreviewDataset,
     #[error("input/output error")]
     InputOutput(#[from] std::io::Error),
     #[error("no download directory available")]
     NoDownloadDirectory,
+    #[error("integrity check failed for file {0}")]
+    IntegrityCheckFailed(String),
 }
 
 #[derive(Debug, Deserialize)]
@@ -164,7 +174,7 @@ impl Default for AssetSearchFilter {
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Default)]
 struct AssetIncludeQuery {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "assetId")]
@@ -177,16 +187,6 @@ struct AssetIncludeQuery {
     asset_name: Option<String>,
 }
 
-impl Default for AssetIncludeQuery {
-    fn default() -> Self {
-        AssetIncludeQuery {
-            asset_id: None,
-            asset_version: None,
-            asset_name: None,
-        }
-    }
-}
-
 #[derive(Debug, Serialize)]
 struct AssetSearchRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -229,9 +229,9 @@ struct AssetResponse {
     #[serde(rename = "labels")]
     labels: Vec<String>,
     #[serde(rename = "primaryType")]
-    primary_type: String,
+    primary_type: AssetType,
     #[serde(rename = "status")]
-    status: String,
+    status: AssetStatus,
     #[serde(rename = "sourceProjectId")]
     source_project_id: String,
     #[serde(rename = "projectIds")]
@@ -247,26 +247,26 @@ struct AssetResponse {
         with = "serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    metadata: Option<Option<::std::collections::HashMap<String, String>>>,
+    metadata: Option<Option<::std::collections::HashMap<String, serde_json::Value>>>,
 }
 
-impl Into<Asset> for AssetResponse {
-    fn into(self) -> Asset {
+impl From<AssetResponse> for Asset {
+    fn from(val: AssetResponse) -> Self {
         Asset::new(
-            AssetIdentity::new(self.asset_id, self.asset_version),
-            self.name,
-            self.description,
-            self.tags,
-            self.system_tags,
-            self.labels,
-            self.primary_type,
-            self.status,
-            self.source_project_id,
-            self.project_ids,
-            self.preview_file,
-            self.preview_file_dataset_id,
-            self.datasets,
-            self.metadata,
+            AssetIdentity::new(val.asset_id, val.asset_version),
+            val.name,
+            val.description,
+            val.tags,
+            val.system_tags,
+            val.labels,
+            val.primary_type,
+            val.status,
+            val.source_project_id,
+            val.project_ids,
+            val.preview_file,
+            val.preview_file_dataset_id,
+            val.datasets,
+            val.metadata,
         )
     }
 }
@@ -285,6 +285,14 @@ impl AssetDeleteRequest {
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct MetadataDefinitionListResponse {
+    #[serde(rename = "next", default)]
+    next: Option<String>,
+    #[serde(rename = "results", default)]
+    results: Vec<MetadataDefinition>,
+}
+
 #[derive(Debug, Deserialize)]
 struct AssetSearchResponse {
     #[serde(rename = "next")]
@@ -293,30 +301,41 @@ struct AssetSearchResponse {
     assets: Vec<AssetResponse>,
 }
 
+/// Request body to create or update an asset.
 #[derive(Debug, Serialize)]
-struct AssetCreateRequest {
+pub struct AssetCreateRequest {
     #[serde(rename = "name")]
     name: String,
     #[serde(rename = "description")]
     description: Option<String>,
     #[serde(rename = "primaryType")]
     primary_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "tags")]
+    tags: Option<Vec<String>>,
     #[serde(
         rename = "metadata",
         default,
         with = "serde_with::rust::double_option",
         skip_serializing_if = "Option::is_none"
     )]
-    metadata: Option<Option<::std::collections::HashMap<String, String>>>,
+    metadata: Option<Option<::std::collections::HashMap<String, serde_json::Value>>>,
 }
 
 impl AssetCreateRequest {
-    fn new(name: String, description: Option<String>) -> Self {
+    pub fn new(
+        name: String,
+        description: Option<String>,
+        primary_type: String,
+        tags: Option<Vec<String>>,
+        metadata: Option<HashMap<String, serde_json::Value>>,
+    ) -> Self {
         Self {
             name,
             description,
-            primary_type: "3D Model".to_string(),
-            metadata: None,
+            primary_type,
+            tags,
+            metadata: metadata.map(Some),
         }
     }
 }
@@ -326,7 +345,8 @@ impl From<Asset> for AssetCreateRequest {
         AssetCreateRequest {
             name: asset.name(),
             description: asset.description(),
-            primary_type: asset.primary_type(),
+            primary_type: asset.primary_type().to_string(),
+            tags: asset.tags(),
             metadata: asset.metadata(),
         }
     }
@@ -342,9 +362,9 @@ pub struct AssetCreateResponse {
     datasets: Vec<Dataset>,
 }
 
-impl Into<AssetIdentity> for AssetCreateResponse {
-    fn into(self) -> AssetIdentity {
-        AssetIdentity::new(self.id, self.version)
+impl From<AssetCreateResponse> for AssetIdentity {
+    fn from(val: AssetCreateResponse) -> Self {
+        AssetIdentity::new(val.id, val.version)
     }
 }
 
@@ -356,14 +376,23 @@ struct FileCreateRequest {
     description: Option<String>,
     #[serde(rename = "fileSize")]
     file_size: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "userChecksum")]
+    user_checksum: Option<String>,
 }
 
 impl FileCreateRequest {
-    fn new(path: String, description: Option<String>, file_size: u64) -> Self {
+    fn new(
+        path: String,
+        description: Option<String>,
+        file_size: u64,
+        user_checksum: Option<String>,
+    ) -> Self {
         Self {
             path,
             description,
             file_size,
+            user_checksum,
         }
     }
 }
@@ -374,6 +403,126 @@ struct FileCreateResponse {
     upload_url: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct FileResponse {
+    #[serde(rename = "filePath")]
+    file_path: String,
+    #[serde(rename = "fileSize")]
+    file_size: Option<u64>,
+    #[serde(rename = "userChecksum")]
+    user_checksum: Option<String>,
+    #[serde(rename = "status")]
+    status: Option<String>,
+    #[serde(rename = "description", default)]
+    description: Option<String>,
+    #[serde(rename = "tags", default)]
+    tags: Option<Vec<String>>,
+    #[serde(rename = "metadata", default)]
+    metadata: Option<HashMap<String, serde_json::Value>>,
+    #[serde(rename = "datasetIds", default)]
+    dataset_ids: Option<Vec<String>>,
+}
+
+impl From<FileResponse> for AssetFile {
+    fn from(val: FileResponse) -> Self {
+        let mut file = AssetFile::new(val.file_path, val.file_size, val.user_checksum, val.status);
+        file.set_description(val.description);
+        file.set_tags(val.tags);
+        file.set_metadata(val.metadata);
+        file.set_dataset_ids(val.dataset_ids.unwrap_or_default());
+        file
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TransformationListResponse {
+    #[serde(default)]
+    transformations: Vec<Transformation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TransformationStartResponse {
+    #[serde(rename = "transformationId")]
+    transformation_id: String,
+}
+
+/// Request body to search the versions of an asset.
+#[derive(Debug, Serialize)]
+struct AssetVersionSearchRequest {
+    #[serde(rename = "pagination")]
+    pagination: PaginationRequest,
+}
+
+#[derive(Debug, Deserialize)]
+struct AssetVersionSearchResponse {
+    #[serde(rename = "next", default)]
+    next: Option<String>,
+    #[serde(rename = "assets", default)]
+    versions: Vec<AssetVersion>,
+}
+
+/// Request body to create a new version of an asset.
+#[derive(Debug, Serialize)]
+struct AssetVersionCreateRequest {
+    #[serde(rename = "parentAssetVersion")]
+    parent_version: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AssetVersionCreateResponse {
+    #[serde(rename = "assetVersion")]
+    version: String,
+}
+
+/// Request body to freeze a version of an asset.
+#[derive(Debug, Serialize)]
+struct AssetVersionFreezeRequest {
+    #[serde(rename = "changeLog")]
+    changelog: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DatasetListResponse {
+    #[serde(rename = "datasets", default)]
+    datasets: Vec<Dataset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DatasetCreateResponse {
+    #[serde(rename = "datasetId")]
+    dataset_id: String,
+}
+
+/// Request body to create a dataset.
+#[derive(Debug, Serialize)]
+struct DatasetCreateRequest {
+    #[serde(rename = "name")]
+    name: String,
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(rename = "primaryType", skip_serializing_if = "Option::is_none")]
+    primary_type: Option<String>,
+    #[serde(rename = "tags", skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+}
+
+impl From<&Dataset> for DatasetCreateRequest {
+    fn from(dataset: &Dataset) -> DatasetCreateRequest {
+        DatasetCreateRequest {
+            name: dataset.name(),
+            description: dataset.description(),
+            primary_type: dataset.primary_type(),
+            tags: dataset.tags(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct FileListResponse {
+    #[serde(rename = "files")]
+    files: Vec<FileResponse>,
+}
+
 #[derive(Debug, Deserialize)]
 struct AssetDownloadUrlResponse {
     #[serde(rename = "filePath")]
@@ -388,20 +537,20 @@ struct AllAssetDownloadUrlsResponse {
     files: Vec<AssetDownloadUrlResponse>,
 }
 
-const UNITY_TOKEN_EXCHANGE_URL: &'static str = "https://services.api.unity.com/auth/v1/token-exchange?projectId={PROJECT_ID}&environmentId={ENVIRONMENT_ID}";
-const UNITY_PRODUCTION_SERVICES_BASE_URL: &'static str = "https://services.unity.com/api"; //"https://services.api.unity.com";
-const UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL: &'static str =
-    "https://services.api.unity.com";
+const UNITY_TOKEN_EXCHANGE_URL: &str = "https://services.api.unity.com/auth/v1/token-exchange?projectId={PROJECT_ID}&environmentId={ENVIRONMENT_ID}";
+const UNITY_PRODUCTION_SERVICES_BASE_URL: &str = "https://services.unity.com/api"; //"https://services.api.unity.com";
+const UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL: &str = "https://services.api.unity.com";
 
 /// Lower-level HTTP client abstraction.
 #[derive(Debug)]
 pub struct Client {
-    http: HttpClient,        // low-level HTTP client object
-    organization_id: String, // Unity organization ID
-    project_id: String,      // Unity project ID
-    environment_id: String,  // Unity environment ID
-    client_id: String,       // Unity Key ID for authenticaion via a service account
-    client_secret: String,   // Unity Key Secret for authentication via a service account
+    http: HttpClient,                                // low-level HTTP client object
+    organization_id: String,                         // Unity organization ID
+    project_id: String,                              // Unity project ID
+    environment_id: String,                          // Unity environment ID
+    client_id: String,     // Unity Key ID for authenticaion via a service account
+    client_secret: String, // Unity Key Secret for authentication via a service account
+    latest_versions: Mutex<HashMap<String, String>>, // resolved latest version of each asset
 }
 
 impl Client {
@@ -422,7 +571,7 @@ impl Client {
         client_secret: String,
     ) -> Result<Client, ClientError> {
         let connection_timeout = Duration::from_secs(30);
-        let request_timeout = connection_timeout.clone();
+        let request_timeout = connection_timeout;
 
         let http = HttpClient::builder()
             .user_agent("uamcli")
@@ -437,6 +586,7 @@ impl Client {
             environment_id,
             client_id,
             client_secret,
+            latest_versions: Mutex::new(HashMap::new()),
         };
 
         Ok(client)
@@ -452,7 +602,7 @@ impl Client {
         let combined_credentials = [client_id.clone(), client_secret.clone()]
             .join(":")
             .to_owned();
-        let encoded_credentials = general_purpose::STANDARD.encode(combined_credentials.to_owned());
+        let encoded_credentials = general_purpose::STANDARD.encode(&combined_credentials);
         let mut authorization_header_value = String::from("Basic ");
         authorization_header_value.push_str(encoded_credentials.as_str());
 
@@ -512,11 +662,15 @@ impl Client {
     /// * asset_identity: a reference to the asset identity for the asset that will contain this file
     /// * dataset_id: a Unity Dataset ID to contain the file
     /// * local_file_path: local path to the file to be uploaded
+    /// * remote_file_path: path of the file within the dataset
+    /// * user_checksum: optional checksum of the local file to be stored with the file record
     async fn create_file(
         &self,
         asset_identity: &AssetIdentity,
         dataset_id: &String,
         local_file_path: &Path,
+        remote_file_path: &str,
+        user_checksum: Option<String>,
     ) -> Result<FileCreateResponse, ClientError> {
         log::trace!("Requesting remote file creation...");
 
@@ -524,7 +678,10 @@ impl Client {
         let mut token_values: HashMap<String, String> = HashMap::new();
         token_values.insert("projectId".to_string(), self.project_id.to_owned());
         token_values.insert("assetId".to_string(), asset_identity.id());
-        token_values.insert("assetVersion".to_string(), asset_identity.version());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(asset_identity).await?,
+        );
         token_values.insert("datasetId".to_string(), dataset_id.to_owned());
         let path = strfmt("/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets/{datasetId}/files", &token_values).unwrap();
         url.push_str(path.as_str());
@@ -532,9 +689,8 @@ impl Client {
         let file = File::open(local_file_path)?;
         let file_size = file.metadata().unwrap().len();
         log::trace!("File size is {}", file_size);
-        let file_name = local_file_path.file_name().unwrap();
         let file_create_request =
-            FileCreateRequest::new(String::from(file_name.to_string_lossy()), None, file_size);
+            FileCreateRequest::new(remote_file_path.to_owned(), None, file_size, user_checksum);
 
         log::trace!("{:?}", &file_create_request);
 
@@ -572,30 +728,72 @@ impl Client {
     /// To successlufly upload a file, you need to first create a file record in Unity.
     /// You can do that by calling the create_file method here.
     /// Once the file is uploaded successfully, You need to call the finalize_file_upload method.
+    /// After finalizing, the remote file record is verified against the local size and checksum,
+    /// as far as the service returns them.
     ///
     /// Parameters:
     /// * asset_identity: a reference to the asset identity
     /// * dataset_id: a reference to the Unity Dataset ID
     /// * local_file_path: a reference to the path for the file to be uploaded
+    /// * checksum_algorithm: the algorithm used to compute the user checksum
     pub async fn upload_file(
         &self,
         asset_identity: &AssetIdentity,
         dataset_id: &String,
         local_file_path: &Path,
+        checksum_algorithm: &ChecksumAlgorithm,
     ) -> Result<(), ClientError> {
         let file_name = String::from(local_file_path.file_name().unwrap().to_string_lossy());
-        let remote_file_path = file_name.to_owned();
+        self.upload_file_as(
+            asset_identity,
+            dataset_id,
+            local_file_path,
+            &file_name,
+            checksum_algorithm,
+        )
+        .await
+    }
+
+    /// Uploads a file to the Unity Storage Service under the given path within the dataset.
+    ///
+    /// Same as upload_file, but the remote path does not have to match the local file name
+    /// (e.g. a path relative to a local directory, like textures/wood.png).
+    ///
+    /// Parameters:
+    /// * asset_identity: a reference to the asset identity
+    /// * dataset_id: a reference to the Unity Dataset ID
+    /// * local_file_path: a reference to the path for the file to be uploaded
+    /// * file_name: the path of the file within the dataset
+    /// * checksum_algorithm: the algorithm used to compute the user checksum
+    pub async fn upload_file_as(
+        &self,
+        asset_identity: &AssetIdentity,
+        dataset_id: &String,
+        local_file_path: &Path,
+        file_name: &str,
+        checksum_algorithm: &ChecksumAlgorithm,
+    ) -> Result<(), ClientError> {
+        let file_name = file_name.to_owned();
         let remote_file_path: String =
-            url::form_urlencoded::byte_serialize(remote_file_path.as_bytes()).collect();
+            url::form_urlencoded::byte_serialize(file_name.as_bytes()).collect();
         let path_str = String::from(local_file_path.to_string_lossy());
         log::trace!("Uploading file {} to the Unity Asset Manager", path_str);
 
+        let checksum = checksum_algorithm.compute(local_file_path)?;
+        log::trace!("File {} checksum is {}", checksum_algorithm, checksum);
+
         let create_result = self
-            .create_file(asset_identity, dataset_id, local_file_path)
+            .create_file(
+                asset_identity,
+                dataset_id,
+                local_file_path,
+                &file_name,
+                Some(checksum.to_owned()),
+            )
             .await?;
 
         let url = create_result.upload_url.to_owned();
-        log::trace!("PUT {}", url.to_string());
+        log::trace!("PUT {}", url);
 
         log::trace!(
             "Uploading file {} to {} as {}...",
@@ -625,46 +823,69 @@ impl Client {
             self.finalize_file_upload(asset_identity, &file_name)
                 .await?;
 
+            let remote_file = self
+                .get_file(asset_identity, dataset_id, &file_name)
+                .await?;
+            if remote_file
+                .size()
+                .is_some_and(|remote_size| remote_size != file_size)
+                || remote_file
+                    .user_checksum()
+                    .is_some_and(|remote_checksum| !remote_checksum.eq_ignore_ascii_case(&checksum))
+            {
+                log::error!(
+                    "Remote file {} does not match the local file (size: {:?}, checksum: {:?})",
+                    file_name,
+                    remote_file.size(),
+                    remote_file.user_checksum()
+                );
+                return Err(ClientError::IntegrityCheckFailed(file_name));
+            }
+
             Ok(())
         } else {
             Err(ClientError::UnexpectedResponse(status))
         }
     }
 
-    /// Marks the file upload as finalized in the Unity Asset Manager.
+    /// Returns the file record for a file in a dataset.
     ///
     /// Parameters:
     /// * asset_identity: a reference to the asset identity
-    /// * file_name: a reference to the file name as uploaded to the Unity Storage service
-    async fn finalize_file_upload(
+    /// * dataset_id: a reference to the Unity Dataset ID
+    /// * file_path: a reference to the file path within the dataset
+    pub async fn get_file(
         &self,
         asset_identity: &AssetIdentity,
-        file_name: &String,
-    ) -> Result<(), ClientError> {
-        log::trace!("Finalizing file upload for remote file {}...", &file_name);
-
+        dataset_id: &String,
+        file_path: &str,
+    ) -> Result<AssetFile, ClientError> {
         let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_URL.to_string();
         let mut token_values: HashMap<String, String> = HashMap::new();
         token_values.insert("projectId".to_string(), self.project_id.to_owned());
         token_values.insert("assetId".to_string(), asset_identity.id());
-        token_values.insert("assetVersion".to_string(), asset_identity.version());
-        token_values.insert("fileName".to_string(), file_name.to_owned());
-        let url_path = strfmt("/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/files/{fileName}/finalize", &token_values).unwrap();
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(asset_identity).await?,
+        );
+        token_values.insert("datasetId".to_string(), dataset_id.to_owned());
+        token_values.insert("filePath".to_string(), encode(file_path).to_string());
+        let path = strfmt("/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets/{datasetId}/files/{filePath}", &token_values).unwrap();
+        url.push_str(path.as_str());
 
-        url.push_str(url_path.as_str());
+        log::trace!("Reading file data...");
+        log::trace!("GET {}", url);
 
-        log::trace!("POST {}", url);
         let response = self
             .http
-            .post(url)
+            .get(url)
             .header("cache-control", "no-cache")
-            .header("content-length", "0")
-            .header("accept", "application/json")
-            .timeout(Duration::from_secs(120))
+            .timeout(Duration::from_secs(30))
             .basic_auth(
                 self.client_id.to_owned(),
                 Some(self.client_secret.to_owned()),
             )
+            .query(&[("IncludeFields", "*")])
             .send()
             .await?;
 
@@ -673,36 +894,49 @@ impl Client {
             let content = response.text().await?;
 
             log::trace!("Response: {}", content);
-            Ok(())
+
+            let response = serde_json::from_str::<FileResponse>(&content)?;
+
+            Ok(response.into())
         } else {
-            Err(ClientError::UnexpectedResponse(status))
+            match status {
+                StatusCode::NOT_FOUND => Err(ClientError::NotFound),
+                _ => Err(ClientError::UnexpectedResponse(status)),
+            }
         }
     }
 
-    /// Returns the download URLs for an existing asset.
+    /// Deletes a file from a dataset.
     ///
     /// Parameters:
-    ///
-    /// * asset_identity: a reference to the asset indetity
-    async fn get_asset_download_urls(
+    /// * asset_identity: a reference to the asset identity
+    /// * dataset_id: a reference to the Unity Dataset ID
+    /// * file_path: a reference to the file path within the dataset
+    pub async fn delete_file(
         &self,
         asset_identity: &AssetIdentity,
-    ) -> Result<AllAssetDownloadUrlsResponse, ClientError> {
-        log::trace!("Reading all download URLs for asset...");
-
+        dataset_id: &String,
+        file_path: &str,
+    ) -> Result<(), ClientError> {
         let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_URL.to_string();
         let mut token_values: HashMap<String, String> = HashMap::new();
         token_values.insert("projectId".to_string(), self.project_id.to_owned());
         token_values.insert("assetId".to_string(), asset_identity.id());
-        token_values.insert("assetVersion".to_string(), asset_identity.version());
-        let path = strfmt("/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/download-urls", &token_values).unwrap();
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(asset_identity).await?,
+        );
+        token_values.insert("datasetId".to_string(), dataset_id.to_owned());
+        token_values.insert("filePath".to_string(), encode(file_path).to_string());
+        let path = strfmt("/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets/{datasetId}/files/{filePath}", &token_values).unwrap();
         url.push_str(path.as_str());
 
-        log::trace!("GET {}", url);
+        log::trace!("Deleting file {}...", file_path);
+        log::trace!("DELETE {}", url);
 
         let response = self
             .http
-            .get(url)
+            .delete(url)
             .header("cache-control", "no-cache")
             .timeout(Duration::from_secs(30))
             .basic_auth(
@@ -717,79 +951,295 @@ impl Client {
             let content = response.text().await?;
 
             log::trace!("Response: {}", content);
-
-            let response = serde_yaml::from_str::<AllAssetDownloadUrlsResponse>(&content).unwrap();
-
-            Ok(response)
+            Ok(())
         } else {
+            let content = response.text().await;
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
+            }
             Err(ClientError::UnexpectedResponse(status))
         }
     }
 
-    /// Doanloads all files associated with a Unity asset.
+    /// Updates the description, tags and metadata of a file.
     ///
     /// Parameters:
-    ///
     /// * asset_identity: a reference to the asset identity
-    /// * output_path: an optional reference to a target download directory path. If None, files will be downloaded to the OS default download directory
-    pub async fn download_all_asset_files(
+    /// * dataset_id: a reference to the Unity Dataset ID
+    /// * file_path: a reference to the file path within the dataset
+    /// * changes: the fields to be changed
+    pub async fn update_file(
         &self,
         asset_identity: &AssetIdentity,
-        output_path: Option<&PathBuf>,
+        dataset_id: &String,
+        file_path: &str,
+        changes: &AssetFileUpdate,
     ) -> Result<(), ClientError> {
-        log::trace!(
-            "Downloading files for asset id={}, version={}",
-            asset_identity.id(),
-            asset_identity.version()
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), asset_identity.id());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(asset_identity).await?,
         );
-        let urls: Vec<(PathBuf, Url)> = self
-            .get_asset_download_urls(asset_identity)
-            .await?
-            .files
-            .into_iter()
-            .map(|i| (PathBuf::from(i.file_path), i.url))
-            .collect();
+        token_values.insert("datasetId".to_string(), dataset_id.to_owned());
+        token_values.insert("filePath".to_string(), encode(file_path).to_string());
+        let path = strfmt("/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets/{datasetId}/files/{filePath}", &token_values).unwrap();
+        url.push_str(path.as_str());
 
-        for url in urls {
-            self.download_file(url, output_path.to_owned()).await?;
+        log::trace!("Updating file {}...", file_path);
+        log::trace!("PATCH {}", url);
+        log::trace!("Request: {:?}", changes);
+
+        let response = self
+            .http
+            .patch(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .json(changes)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+            Ok(())
+        } else {
+            let content = response.text().await;
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
+            }
+            match status {
+                StatusCode::NOT_FOUND => Err(ClientError::NotFound),
+                _ => Err(ClientError::UnexpectedResponse(status)),
+            }
         }
-        Ok(())
     }
 
-    /// Downloads a single file associated to an asset.
+    /// Returns all file records in a dataset.
     ///
     /// Parameters:
-    ///
-    /// * item: tuple containing the file's path and the download URL
-    /// * output_path: optional reference to a target download directory. If Non, the default OS download directory will be used
-    async fn download_file(
+    /// * asset_identity: a reference to the asset identity
+    /// * dataset_id: a reference to the Unity Dataset ID
+    pub async fn list_files(
         &self,
-        item: (PathBuf, Url),
-        output_path: Option<&PathBuf>,
-    ) -> Result<(), ClientError> {
-        let path = match output_path {
-            Some(path) => Some(path.to_owned()),
-            None => dirs::download_dir(),
-        };
-
-        match path {
-            Some(path) => {
-                let path = path.join(item.0);
+        asset_identity: &AssetIdentity,
+        dataset_id: &String,
+    ) -> Result<Vec<AssetFile>, ClientError> {
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), asset_identity.id());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(asset_identity).await?,
+        );
+        token_values.insert("datasetId".to_string(), dataset_id.to_owned());
+        let path = strfmt("/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets/{datasetId}/files", &token_values).unwrap();
+        url.push_str(path.as_str());
 
-                log::trace!(
-                    "Downloading file from URL {} to local path {}...",
-                    item.1,
-                    path.clone().into_os_string().into_string().unwrap()
-                );
+        log::trace!("Listing files in dataset {}...", dataset_id);
+        log::trace!("GET {}", url);
 
-                let response = reqwest::get(item.1).await?;
-                let status = &response.status();
-                if status.is_success() {
-                    let mut file = { File::create(path)? };
-                    let mut stream = response.bytes_stream();
-                    while let Some(item) = stream.next().await {
-                        let chunk = item.unwrap();
-                        file.write_all(&chunk)?;
+        let response = self
+            .http
+            .get(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .query(&[("IncludeFields", "*")])
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+
+            let response = serde_json::from_str::<FileListResponse>(&content)?;
+
+            Ok(response.files.into_iter().map(|f| f.into()).collect())
+        } else {
+            Err(ClientError::UnexpectedResponse(status))
+        }
+    }
+
+    /// Marks the file upload as finalized in the Unity Asset Manager.
+    ///
+    /// Parameters:
+    /// * asset_identity: a reference to the asset identity
+    /// * file_name: a reference to the file name as uploaded to the Unity Storage service
+    async fn finalize_file_upload(
+        &self,
+        asset_identity: &AssetIdentity,
+        file_name: &String,
+    ) -> Result<(), ClientError> {
+        log::trace!("Finalizing file upload for remote file {}...", &file_name);
+
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), asset_identity.id());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(asset_identity).await?,
+        );
+        token_values.insert("fileName".to_string(), encode(file_name).to_string());
+        let url_path = strfmt("/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/files/{fileName}/finalize", &token_values).unwrap();
+
+        url.push_str(url_path.as_str());
+
+        log::trace!("POST {}", url);
+        let response = self
+            .http
+            .post(url)
+            .header("cache-control", "no-cache")
+            .header("content-length", "0")
+            .header("accept", "application/json")
+            .timeout(Duration::from_secs(120))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+            Ok(())
+        } else {
+            Err(ClientError::UnexpectedResponse(status))
+        }
+    }
+
+    /// Returns the download URLs for an existing asset.
+    ///
+    /// Parameters:
+    ///
+    /// * asset_identity: a reference to the asset indetity
+    async fn get_asset_download_urls(
+        &self,
+        asset_identity: &AssetIdentity,
+    ) -> Result<AllAssetDownloadUrlsResponse, ClientError> {
+        log::trace!("Reading all download URLs for asset...");
+
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), asset_identity.id());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(asset_identity).await?,
+        );
+        let path = strfmt("/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/download-urls", &token_values).unwrap();
+        url.push_str(path.as_str());
+
+        log::trace!("GET {}", url);
+
+        let response = self
+            .http
+            .get(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+
+            let response = serde_yaml::from_str::<AllAssetDownloadUrlsResponse>(&content).unwrap();
+
+            Ok(response)
+        } else {
+            Err(ClientError::UnexpectedResponse(status))
+        }
+    }
+
+    /// Doanloads all files associated with a Unity asset.
+    ///
+    /// Parameters:
+    ///
+    /// * asset_identity: a reference to the asset identity
+    /// * output_path: an optional reference to a target download directory path. If None, files will be downloaded to the OS default download directory
+    pub async fn download_all_asset_files(
+        &self,
+        asset_identity: &AssetIdentity,
+        output_path: Option<&PathBuf>,
+    ) -> Result<(), ClientError> {
+        log::trace!(
+            "Downloading files for asset id={}, version={}",
+            asset_identity.id(),
+            asset_identity.version()
+        );
+        let urls: Vec<(PathBuf, Url)> = self
+            .get_asset_download_urls(asset_identity)
+            .await?
+            .files
+            .into_iter()
+            .map(|i| (PathBuf::from(i.file_path), i.url))
+            .collect();
+
+        for url in urls {
+            self.download_file(url, output_path.to_owned()).await?;
+        }
+        Ok(())
+    }
+
+    /// Downloads a single file associated to an asset.
+    ///
+    /// Parameters:
+    ///
+    /// * item: tuple containing the file's path and the download URL
+    /// * output_path: optional reference to a target download directory. If Non, the default OS download directory will be used
+    async fn download_file(
+        &self,
+        item: (PathBuf, Url),
+        output_path: Option<&PathBuf>,
+    ) -> Result<(), ClientError> {
+        let path = match output_path {
+            Some(path) => Some(path.to_owned()),
+            None => dirs::download_dir(),
+        };
+
+        match path {
+            Some(path) => {
+                let path = Self::download_path(&path, &item.0)?;
+
+                log::trace!(
+                    "Downloading file from URL {} to local path {}...",
+                    item.1,
+                    path.clone().into_os_string().into_string().unwrap()
+                );
+
+                let response = reqwest::get(item.1).await?;
+                let status = &response.status();
+                if status.is_success() {
+                    let mut file = { File::create(path)? };
+                    let mut stream = response.bytes_stream();
+                    while let Some(item) = stream.next().await {
+                        let chunk = item.unwrap();
+                        file.write_all(&chunk)?;
                     }
 
                     Ok(())
@@ -797,104 +1247,876 @@ impl Client {
                     Err(ClientError::UnexpectedResponse(*status))
                 }
             }
-            None => Err(ClientError::NoDownloadDirectory),
+            None => Err(ClientError::NoDownloadDirectory),
+        }
+    }
+
+    /// Returns the local path of a downloaded file and creates its parent directories,
+    /// since the file path of an asset file may contain directories.
+    ///
+    /// Parameters:
+    ///
+    /// * directory - the target download directory
+    /// * file_path - the path of the file in the asset
+    fn download_path(directory: &Path, file_path: &Path) -> Result<PathBuf, ClientError> {
+        let path = directory.join(file_path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        Ok(path)
+    }
+
+    /// Sets the dataset pripary type.
+    ///
+    /// Parameters:
+    /// * asset_identity - the asset identity (ID and Version)
+    /// * name - dataset name
+    /// * type - dataset type (e.g. "3D Model")
+    async fn set_dataset_type(
+        &self,
+        asset_identity: &AssetIdentity,
+        dataset_id: String,
+        dataset_name: String,
+        primary_type: String,
+    ) -> Result<(), ClientError> {
+        log::trace!("Upldating dataset primary type...");
+
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), asset_identity.id());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(asset_identity).await?,
+        );
+        token_values.insert("datasetId".to_string(), dataset_id.to_owned());
+        let path = strfmt(
+            "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets/{datasetId}",
+            &token_values,
+        )
+        .unwrap();
+        url.push_str(path.as_str());
+
+        let dataset_update_request = Dataset::new(dataset_id, dataset_name, Some(primary_type));
+
+        log::trace!("PATCH {}", url);
+        let response = self
+            .http
+            .patch(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .json(&dataset_update_request)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(())
+        } else {
+            Err(ClientError::UnexpectedResponse(status))
+        }
+    }
+
+    /// Returns the version ID of the asset identity. The latest keyword is resolved to the ID
+    /// of the latest version, which is cached for the lifetime of the client.
+    ///
+    /// Parameters:
+    /// * identity - the asset identity (ID and version)
+    async fn asset_version(&self, identity: &AssetIdentity) -> Result<String, ClientError> {
+        if !identity.is_latest() {
+            return Ok(identity.version());
+        }
+        if let Some(version) = self.latest_versions.lock().unwrap().get(&identity.id()) {
+            return Ok(version.to_owned());
+        }
+
+        let versions = self.list_asset_versions(&identity.id()).await?;
+        let version = AssetVersion::latest(&versions)
+            .map(|v| v.version())
+            .ok_or(ClientError::NoAssetVersion(identity.id()))?;
+        log::trace!("Latest version of asset {} is {}", identity.id(), version);
+        self.latest_versions
+            .lock()
+            .unwrap()
+            .insert(identity.id(), version.to_owned());
+
+        Ok(version)
+    }
+
+    /// Returns all versions of an asset.
+    ///
+    /// Parameters:
+    /// * asset_id - the asset ID
+    pub async fn list_asset_versions(
+        &self,
+        asset_id: &str,
+    ) -> Result<Vec<AssetVersion>, ClientError> {
+        let mut next: Option<String> = None;
+        let mut versions: Vec<AssetVersion> = Vec::new();
+
+        loop {
+            let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_URL.to_string();
+            let mut token_values: HashMap<String, String> = HashMap::new();
+            token_values.insert("projectId".to_string(), self.project_id.to_owned());
+            token_values.insert("assetId".to_string(), asset_id.to_owned());
+            let path = strfmt(
+                "/assets/v1/projects/{projectId}/assets/{assetId}/versions/search",
+                &token_values,
+            )
+            .unwrap();
+            url.push_str(path.as_str());
+
+            let request = AssetVersionSearchRequest {
+                pagination: PaginationRequest::new(
+                    next,
+                    50,
+                    String::from("versionNumber"),
+                    SortingOrder::Descending,
+                ),
+            };
+
+            log::trace!("POST {}", url);
+            log::trace!("Request: {:?}", &request);
+
+            let response = self
+                .http
+                .post(url)
+                .header("cache-control", "no-cache")
+                .timeout(Duration::from_secs(30))
+                .basic_auth(
+                    self.client_id.to_owned(),
+                    Some(self.client_secret.to_owned()),
+                )
+                .query(&[("includeFields", "*")])
+                .json(&request)
+                .send()
+                .await?;
+
+            let status = response.status();
+            if status.is_success() {
+                let content = response.text().await?;
+
+                log::trace!("Response: {}", content);
+
+                let mut response: AssetVersionSearchResponse = serde_json::from_str(&content)?;
+                versions.append(&mut response.versions);
+                next = response.next.filter(|n| !n.is_empty());
+                if next.is_none() {
+                    break;
+                }
+            } else {
+                return Err(ClientError::UnexpectedResponse(status));
+            }
+        }
+
+        Ok(versions)
+    }
+
+    /// Returns the details of an asset version. Returns None if such version does not exist.
+    ///
+    /// Parameters:
+    /// * identity - the asset identity (ID and version)
+    pub async fn get_asset_version(
+        &self,
+        identity: &AssetIdentity,
+    ) -> Result<Option<AssetVersion>, ClientError> {
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), identity.id());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(identity).await?,
+        );
+        let path = strfmt(
+            "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}",
+            &token_values,
+        )
+        .unwrap();
+        url.push_str(path.as_str());
+
+        log::trace!("GET {}", url);
+
+        let response = self
+            .http
+            .get(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .query(&[("IncludeFields", "*")])
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+
+            let version: AssetVersion = serde_json::from_str(&content)?;
+
+            Ok(Some(version))
+        } else {
+            match status {
+                StatusCode::NOT_FOUND => Ok(None),
+                _ => Err(ClientError::UnexpectedResponse(status)),
+            }
+        }
+    }
+
+    /// Creates a new version of an asset from a parent version and returns its ID.
+    /// The new version starts with the datasets and files of the parent version.
+    ///
+    /// Parameters:
+    /// * identity - the asset identity with the parent version
+    pub async fn create_asset_version(
+        &self,
+        identity: &AssetIdentity,
+    ) -> Result<String, ClientError> {
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), identity.id());
+        let path = strfmt(
+            "/assets/v1/projects/{projectId}/assets/{assetId}/versions",
+            &token_values,
+        )
+        .unwrap();
+        url.push_str(path.as_str());
+
+        let request = AssetVersionCreateRequest {
+            parent_version: self.asset_version(identity).await?,
+        };
+
+        log::trace!("Creating a version of asset {}...", identity.id());
+        log::trace!("POST {}", url);
+        log::trace!("POST Body: {:?}", request);
+
+        let response = self
+            .http
+            .post(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .json(&request)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+
+            let response: AssetVersionCreateResponse = serde_json::from_str(&content)?;
+            // the new version is the latest one now
+            self.latest_versions.lock().unwrap().remove(&identity.id());
+
+            Ok(response.version)
+        } else {
+            let content = response.text().await;
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
+            }
+            Err(ClientError::UnexpectedResponse(status))
+        }
+    }
+
+    /// Freezes an asset version with a change log message. A frozen version gets a version number
+    /// and cannot be changed anymore.
+    ///
+    /// Parameters:
+    /// * identity - the asset identity (ID and version)
+    /// * changelog - the description of the changes in this version
+    pub async fn freeze_asset_version(
+        &self,
+        identity: &AssetIdentity,
+        changelog: &str,
+    ) -> Result<(), ClientError> {
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), identity.id());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(identity).await?,
+        );
+        let path = strfmt(
+            "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/freeze",
+            &token_values,
+        )
+        .unwrap();
+        url.push_str(path.as_str());
+
+        let request = AssetVersionFreezeRequest {
+            changelog: changelog.to_owned(),
+        };
+
+        log::trace!("POST {}", url);
+        log::trace!("POST Body: {:?}", request);
+
+        let response = self
+            .http
+            .post(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .json(&request)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+            self.latest_versions.lock().unwrap().remove(&identity.id());
+            Ok(())
+        } else {
+            let content = response.text().await;
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
+            }
+            Err(ClientError::UnexpectedResponse(status))
+        }
+    }
+
+    /// Returns the datasets of an asset version.
+    ///
+    /// Parameters:
+    /// * asset_identity - the asset identity (ID and version)
+    pub async fn list_datasets(
+        &self,
+        asset_identity: &AssetIdentity,
+    ) -> Result<Vec<Dataset>, ClientError> {
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), asset_identity.id());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(asset_identity).await?,
+        );
+        let path = strfmt(
+            "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets",
+            &token_values,
+        )
+        .unwrap();
+        url.push_str(path.as_str());
+
+        log::trace!("Listing datasets...");
+        log::trace!("GET {}", url);
+
+        let response = self
+            .http
+            .get(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+
+            let response: DatasetListResponse = serde_json::from_str(&content)?;
+
+            Ok(response.datasets)
+        } else {
+            Err(ClientError::UnexpectedResponse(status))
+        }
+    }
+
+    /// Returns the details of a dataset. Returns None if such dataset does not exist.
+    ///
+    /// Parameters:
+    /// * asset_identity - the asset identity (ID and version)
+    /// * dataset_id - the ID of the dataset
+    pub async fn get_dataset(
+        &self,
+        asset_identity: &AssetIdentity,
+        dataset_id: &str,
+    ) -> Result<Option<Dataset>, ClientError> {
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), asset_identity.id());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(asset_identity).await?,
+        );
+        token_values.insert("datasetId".to_string(), dataset_id.to_owned());
+        let path = strfmt(
+            "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets/{datasetId}",
+            &token_values,
+        )
+        .unwrap();
+        url.push_str(path.as_str());
+
+        log::trace!("GET {}", url);
+
+        let response = self
+            .http
+            .get(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+
+            let dataset: Dataset = serde_json::from_str(&content)?;
+
+            Ok(Some(dataset))
+        } else {
+            match status {
+                StatusCode::NOT_FOUND => Ok(None),
+                _ => Err(ClientError::UnexpectedResponse(status)),
+            }
+        }
+    }
+
+    /// Creates a dataset in an asset version and returns its ID.
+    ///
+    /// Parameters:
+    /// * asset_identity - the asset identity (ID and version)
+    /// * dataset - the name, description, primary type and tags of the new dataset. The ID is ignored
+    pub async fn create_dataset(
+        &self,
+        asset_identity: &AssetIdentity,
+        dataset: &Dataset,
+    ) -> Result<String, ClientError> {
+        log::trace!("Creating dataset {}...", dataset.name());
+
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), asset_identity.id());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(asset_identity).await?,
+        );
+        let path = strfmt(
+            "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets",
+            &token_values,
+        )
+        .unwrap();
+        url.push_str(path.as_str());
+
+        let request: DatasetCreateRequest = dataset.into();
+
+        log::trace!("POST {}", url);
+        log::trace!("POST Body: {:?}", request);
+
+        let response = self
+            .http
+            .post(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .json(&request)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+
+            let response: DatasetCreateResponse = serde_json::from_str(&content)?;
+
+            Ok(response.dataset_id)
+        } else {
+            let content = response.text().await;
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
+            }
+            Err(ClientError::UnexpectedResponse(status))
+        }
+    }
+
+    /// Updates the name, description, primary type and tags of a dataset.
+    ///
+    /// Parameters:
+    /// * asset_identity - the asset identity (ID and version)
+    /// * dataset_id - the ID of the dataset
+    /// * changes - the fields to be changed
+    pub async fn update_dataset(
+        &self,
+        asset_identity: &AssetIdentity,
+        dataset_id: &str,
+        changes: &DatasetUpdate,
+    ) -> Result<(), ClientError> {
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), asset_identity.id());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(asset_identity).await?,
+        );
+        token_values.insert("datasetId".to_string(), dataset_id.to_owned());
+        let path = strfmt(
+            "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets/{datasetId}",
+            &token_values,
+        )
+        .unwrap();
+        url.push_str(path.as_str());
+
+        log::trace!("Updating dataset fields...");
+        log::trace!("PATCH {}", url);
+        log::trace!("Request: {:?}", changes);
+
+        let response = self
+            .http
+            .patch(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .json(changes)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+            Ok(())
+        } else {
+            let content = response.text().await;
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
+            }
+            Err(ClientError::UnexpectedResponse(status))
+        }
+    }
+
+    /// Deletes a dataset and its files.
+    ///
+    /// Parameters:
+    /// * asset_identity - the asset identity (ID and version)
+    /// * dataset_id - the ID of the dataset
+    pub async fn delete_dataset(
+        &self,
+        asset_identity: &AssetIdentity,
+        dataset_id: &str,
+    ) -> Result<(), ClientError> {
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), asset_identity.id());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(asset_identity).await?,
+        );
+        token_values.insert("datasetId".to_string(), dataset_id.to_owned());
+        let path = strfmt(
+            "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets/{datasetId}",
+            &token_values,
+        )
+        .unwrap();
+        url.push_str(path.as_str());
+
+        log::trace!("Deleting dataset {}...", dataset_id);
+        log::trace!("DELETE {}", url);
+
+        let response = self
+            .http
+            .delete(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(())
+        } else {
+            let content = response.text().await;
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
+            }
+            Err(ClientError::UnexpectedResponse(status))
+        }
+    }
+
+    /// Starts the thumbnail generator transformation and returns the transformation ID.
+    ///
+    /// Parameters:
+    /// * asset_identity - the asset identity (ID and version)
+    /// * dataset_id - the ID of the dataset containing the input files
+    /// * files - the paths of the input files in the dataset
+    pub async fn generate_thumbnails(
+        &self,
+        asset_identity: &AssetIdentity,
+        dataset_id: &str,
+        files: Vec<String>,
+    ) -> Result<String, ClientError> {
+        log::trace!("Generating thumbnails...");
+
+        let request = TransformationRequest::new(files, HashMap::new());
+        self.start_transformation(asset_identity, dataset_id, "thumbnail-generator", &request)
+            .await
+    }
+
+    /// Starts a transformation workflow on a dataset and returns the transformation ID.
+    ///
+    /// Parameters:
+    /// * asset_identity - the asset identity (ID and version)
+    /// * dataset_id - the ID of the dataset containing the input files
+    /// * workflow_type - the transformation workflow (e.g. "thumbnail-generator")
+    /// * request - the input files and the workflow parameters
+    pub async fn start_transformation(
+        &self,
+        asset_identity: &AssetIdentity,
+        dataset_id: &str,
+        workflow_type: &str,
+        request: &TransformationRequest,
+    ) -> Result<String, ClientError> {
+        log::trace!("Starting transformation {}...", workflow_type);
+
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), asset_identity.id());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(asset_identity).await?,
+        );
+        token_values.insert("datasetId".to_string(), dataset_id.to_owned());
+        token_values.insert(
+            "workflowType".to_string(),
+            encode(workflow_type).to_string(),
+        );
+        let path = strfmt(
+            "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets/{datasetId}/transformations/start/{workflowType}",
+            &token_values,
+        )
+        .unwrap();
+        url.push_str(path.as_str());
+
+        log::trace!("POST {}", url);
+        log::trace!("POST Body: {:?}", request);
+
+        let response = self
+            .http
+            .post(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .json(request)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+
+            let response: TransformationStartResponse = serde_json::from_str(&content)?;
+
+            Ok(response.transformation_id)
+        } else {
+            let content = response.text().await;
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
+            }
+            Err(ClientError::UnexpectedResponse(status))
+        }
+    }
+
+    /// Returns the details of a transformation. Returns None if such transformation does not exist.
+    ///
+    /// Parameters:
+    /// * asset_identity - the asset identity (ID and version)
+    /// * dataset_id - the ID of the dataset the transformation was started on
+    /// * transformation_id - the ID of the transformation
+    pub async fn get_transformation(
+        &self,
+        asset_identity: &AssetIdentity,
+        dataset_id: &str,
+        transformation_id: &str,
+    ) -> Result<Option<Transformation>, ClientError> {
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), asset_identity.id());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(asset_identity).await?,
+        );
+        token_values.insert("datasetId".to_string(), dataset_id.to_owned());
+        token_values.insert("transformationId".to_string(), transformation_id.to_owned());
+        let path = strfmt(
+            "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets/{datasetId}/transformations/{transformationId}",
+            &token_values,
+        )
+        .unwrap();
+        url.push_str(path.as_str());
+
+        log::trace!("GET {}", url);
+
+        let response = self
+            .http
+            .get(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+
+            let transformation: Transformation = serde_json::from_str(&content)?;
+
+            Ok(Some(transformation))
+        } else {
+            match status {
+                StatusCode::NOT_FOUND => Ok(None),
+                _ => Err(ClientError::UnexpectedResponse(status)),
+            }
         }
     }
 
-    /// Sets the dataset pripary type.
+    /// Returns the transformations of an asset, optionally limited to one dataset.
     ///
     /// Parameters:
-    /// * asset_identity - the asset identity (ID and Version)
-    /// * name - dataset name
-    /// * type - dataset type (e.g. "3D Model")
-    async fn set_dataset_type(
+    /// * asset_identity - the asset identity (ID and version)
+    /// * dataset_id - (optional) the ID of the dataset
+    pub async fn list_transformations(
         &self,
         asset_identity: &AssetIdentity,
-        dataset_id: String,
-        dataset_name: String,
-        primary_type: String,
-    ) -> Result<(), ClientError> {
-        log::trace!("Upldating dataset primary type...");
-
+        dataset_id: Option<&str>,
+    ) -> Result<Vec<Transformation>, ClientError> {
         let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL.to_string();
         let mut token_values: HashMap<String, String> = HashMap::new();
         token_values.insert("projectId".to_string(), self.project_id.to_owned());
-        token_values.insert("assetId".to_string(), asset_identity.id());
-        token_values.insert("assetVersion".to_string(), asset_identity.version());
-        token_values.insert("datasetId".to_string(), dataset_id.to_owned());
         let path = strfmt(
-            "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets/{datasetId}",
+            "/assets/v1/projects/{projectId}/transformations",
             &token_values,
         )
         .unwrap();
         url.push_str(path.as_str());
 
-        let dataset_update_request = Dataset::new(dataset_id, dataset_name, Some(primary_type));
+        let mut query: Vec<(&str, String)> = vec![
+            ("assetId", asset_identity.id()),
+            ("assetVersion", self.asset_version(asset_identity).await?),
+        ];
+        if let Some(dataset_id) = dataset_id {
+            query.push(("datasetId", dataset_id.to_owned()));
+        }
+
+        log::trace!("GET {}", url);
 
-        log::trace!("PATCH {}", url);
         let response = self
             .http
-            .patch(url)
+            .get(url)
             .header("cache-control", "no-cache")
             .timeout(Duration::from_secs(30))
             .basic_auth(
                 self.client_id.to_owned(),
                 Some(self.client_secret.to_owned()),
             )
-            .json(&dataset_update_request)
+            .query(&query)
             .send()
             .await?;
 
         let status = response.status();
         if status.is_success() {
-            Ok(())
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+
+            let response: TransformationListResponse = serde_json::from_str(&content)?;
+
+            Ok(response.transformations)
         } else {
+            let content = response.text().await;
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
+            }
             Err(ClientError::UnexpectedResponse(status))
         }
     }
 
-    /// Create thumbnails
+    /// Cancels a running transformation.
     ///
     /// Parameters:
-    /// * asset_identity - the asset identity (ID and version)
-    /// * dataset_id - the ID of the dataset
-    pub async fn generate_thumbnails(
-        &self,
-        asset_identity: &AssetIdentity,
-        dataset_id: &String,
-        files: Vec<String>,
-    ) -> Result<(), ClientError> {
-        log::trace!("Generating thumbnails...");
-
+    /// * transformation_id - the ID of the transformation
+    pub async fn cancel_transformation(&self, transformation_id: &str) -> Result<(), ClientError> {
         let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL.to_string();
         let mut token_values: HashMap<String, String> = HashMap::new();
         token_values.insert("projectId".to_string(), self.project_id.to_owned());
-        token_values.insert("assetId".to_string(), asset_identity.id());
-        token_values.insert("assetVersion".to_string(), asset_identity.version());
-        token_values.insert("datasetId".to_string(), dataset_id.to_owned());
-        // https://services.api.unity.com/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets/{datasetId}/transformations/start/{workFlowType}
+        token_values.insert("transformationId".to_string(), transformation_id.to_owned());
         let path = strfmt(
-            "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/datasets/{datasetId}/transformations/start/thumbnail-generator",
+            "/assets/v1/projects/{projectId}/transformations/{transformationId}/terminate",
             &token_values,
         )
         .unwrap();
         url.push_str(path.as_str());
 
-        let thumbnail_generation_request = ThumbnailGenerationRequest::new(files);
-
         log::trace!("POST {}", url);
-        log::trace!("POST Body: {:?}", thumbnail_generation_request);
 
         let response = self
             .http
             .post(url)
             .header("cache-control", "no-cache")
+            .header("content-length", "0")
             .timeout(Duration::from_secs(30))
             .basic_auth(
                 self.client_id.to_owned(),
                 Some(self.client_secret.to_owned()),
             )
-            //.json(&thumbnail_generation_request)
             .send()
             .await?;
 
@@ -902,21 +2124,28 @@ impl Client {
         if status.is_success() {
             Ok(())
         } else {
-            Err(ClientError::UnexpectedResponse(status))
+            let content = response.text().await;
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
+            }
+            match status {
+                StatusCode::NOT_FOUND => Err(ClientError::NotFound),
+                _ => Err(ClientError::UnexpectedResponse(status)),
+            }
         }
     }
 
     /// Creates a new Unity asset. Uploads one or more files to the asset.
     ///
     /// Parameters:
-    /// * name - the name of the asset as it would appear in the Unity Asset Manager UI
-    /// * description - human-friendly asset description
+    /// * asset_create_request - the name, description, primary type, tags and metadata of the new asset. The primary type is also used for the source dataset
     /// * data_files: vector of references to the local files to be uploaded
+    /// * checksum_algorithm: the algorithm used to compute the user checksum of the uploaded files
     pub async fn create_asset(
         &self,
-        name: String,
-        description: Option<String>,
+        asset_create_request: AssetCreateRequest,
         data_files: Vec<&PathBuf>,
+        checksum_algorithm: &ChecksumAlgorithm,
     ) -> Result<AssetIdentity, ClientError> {
         log::trace!("Creating an asset...");
 
@@ -926,7 +2155,7 @@ impl Client {
         let path = strfmt("/assets/v1/projects/{projectId}/assets", &token_values).unwrap();
         url.push_str(path.as_str());
 
-        let asset_create_request = AssetCreateRequest::new(name, description);
+        let primary_type = asset_create_request.primary_type.to_owned();
 
         log::trace!("POST {}", url);
         log::trace!("POST Body: {:?}", asset_create_request);
@@ -964,18 +2193,23 @@ impl Client {
                     );
                     let source_dataset_id = source_dataset.id();
 
-                    // update the "Source" dataset type to be "3D Model"
+                    // the "Source" dataset has the same type as the asset
                     self.set_dataset_type(
                         &identity,
                         source_dataset_id.to_owned(),
                         "Source".to_string(),
-                        "3D Model".to_string(),
+                        primary_type,
                     )
                     .await?;
 
                     for path in &data_files {
-                        self.upload_file(&identity, &source_dataset_id, path.as_path())
-                            .await?;
+                        self.upload_file(
+                            &identity,
+                            &source_dataset_id,
+                            path.as_path(),
+                            checksum_algorithm,
+                        )
+                        .await?;
                     }
 
                     /*
@@ -1012,7 +2246,10 @@ impl Client {
         let mut token_values: HashMap<String, String> = HashMap::new();
         token_values.insert("projectId".to_string(), self.project_id.to_owned());
         token_values.insert("assetId".to_string(), identity.id());
-        token_values.insert("assetVersion".to_string(), identity.version());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(identity).await?,
+        );
         let path = strfmt(
             "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}",
             &token_values,
@@ -1065,7 +2302,10 @@ impl Client {
         let mut token_values: HashMap<String, String> = HashMap::new();
         token_values.insert("projectId".to_string(), self.project_id.to_owned());
         token_values.insert("assetId".to_string(), identity.id());
-        token_values.insert("assetVersion".to_string(), identity.version());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(&identity).await?,
+        );
         let path = strfmt(
             "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}",
             &token_values,
@@ -1099,9 +2339,66 @@ impl Client {
             Ok(())
         } else {
             let content = response.text().await;
-            match content {
-                Ok(content) => log::error!("Error: {}", content),
-                Err(_) => (),
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
+            }
+            Err(ClientError::UnexpectedResponse(status))
+        }
+    }
+
+    /// Updates only the provided fields of an existing asset.
+    ///
+    /// Parameters:
+    ///
+    /// * identity: a reference to the asset identity
+    /// * changes: the fields to be updated
+    pub async fn patch_asset(
+        &self,
+        identity: &AssetIdentity,
+        changes: &AssetUpdate,
+    ) -> Result<(), ClientError> {
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert("projectId".to_string(), self.project_id.to_owned());
+        token_values.insert("assetId".to_string(), identity.id());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(identity).await?,
+        );
+        let path = strfmt(
+            "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}",
+            &token_values,
+        )
+        .unwrap();
+        url.push_str(path.as_str());
+
+        log::trace!("Updating asset fields...");
+        log::trace!("PATCH {}", url);
+        log::trace!("Request: {:?}", changes);
+
+        let response = self
+            .http
+            .patch(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .json(changes)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+            Ok(())
+        } else {
+            let content = response.text().await;
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
             }
             Err(ClientError::UnexpectedResponse(status))
         }
@@ -1145,9 +2442,8 @@ impl Client {
             Ok(())
         } else {
             let content = response.text().await;
-            match content {
-                Ok(content) => log::error!("Error: {}", content),
-                Err(_) => (),
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
             }
             Err(ClientError::UnexpectedResponse(status))
         }
@@ -1156,13 +2452,16 @@ impl Client {
     pub async fn delete_metadata(
         &self,
         identity: &AssetIdentity,
-        keys: &Vec<String>,
+        keys: &[String],
     ) -> Result<(), ClientError> {
         let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_URL.to_string();
         let mut token_values: HashMap<String, String> = HashMap::new();
         token_values.insert("projectId".to_string(), self.project_id.to_owned());
         token_values.insert("assetId".to_string(), identity.id());
-        token_values.insert("assetVersion".to_string(), identity.version());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(identity).await?,
+        );
         let path = strfmt(
             "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/fields",
             &token_values,
@@ -1196,9 +2495,8 @@ impl Client {
             Ok(())
         } else {
             let content = response.text().await;
-            match content {
-                Ok(content) => log::error!("Error: {}", content),
-                Err(_) => (),
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
             }
             Err(ClientError::UnexpectedResponse(status))
         }
@@ -1219,8 +2517,12 @@ impl Client {
         let mut token_values: HashMap<String, String> = HashMap::new();
         token_values.insert("projectId".to_string(), self.project_id.to_owned());
         token_values.insert("assetId".to_string(), identity.id());
-        token_values.insert("assetVersion".to_string(), identity.version());
-        token_values.insert("status".to_string(), status.to_string());
+        token_values.insert(
+            "assetVersion".to_string(),
+            self.asset_version(identity).await?,
+        );
+        // the status endpoint takes the lowercase status name
+        token_values.insert("status".to_string(), status.name().to_lowercase());
         let path = strfmt(
             "/assets/v1/projects/{projectId}/assets/{assetId}/versions/{assetVersion}/status/{status}",
             &token_values,
@@ -1289,7 +2591,7 @@ impl Client {
                     None => (),
                     Some(asset_id) => {
                         query.asset_id = Some(asset_id.id());
-                        query.asset_version = Some(asset_id.version());
+                        query.asset_version = Some(self.asset_version(&asset_id).await?);
                     }
                 }
 
@@ -1420,20 +2722,19 @@ impl Client {
 
     /// Creates a new metadata property fiels definition in Unity Organization.
     ///
-    /// The current implementation only creates field defintions of type 'text'.
-    /// Future versions may allow for the creation of other types available in Unity.
-    ///
     /// Parameters:
     ///
-    /// * name: the name of the desired property.
-    pub async fn register_metadata_definition(&self, name: &String) -> Result<(), ClientError> {
+    /// * definition: the definition of the desired property.
+    pub async fn register_metadata_definition(
+        &self,
+        definition: &MetadataDefinition,
+    ) -> Result<(), ClientError> {
         let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL.to_string();
         let mut token_values: HashMap<String, String> = HashMap::new();
         token_values.insert(
             "organizationId".to_string(),
             self.organization_id.to_owned(),
         );
-        token_values.insert("name".to_string(), encode(name.as_str()).to_string());
 
         let path = strfmt(
             "/assets/v1/organizations/{organizationId}/templates/fields",
@@ -1443,11 +2744,6 @@ impl Client {
         url.push_str(path.as_str());
 
         log::trace!("POST {}", url);
-        let request = MetadataDefinition {
-            name: name.to_owned(),
-            display_name: name.to_owned(),
-            value_type: "text".to_string(),
-        };
 
         let response = self
             .http
@@ -1458,7 +2754,7 @@ impl Client {
                 self.client_id.to_owned(),
                 Some(self.client_secret.to_owned()),
             )
-            .json(&request)
+            .json(definition)
             .send()
             .await?;
 
@@ -1470,11 +2766,200 @@ impl Client {
             Ok(())
         } else {
             let content = response.text().await;
-            match content {
-                Ok(content) => log::error!("Error: {}", content.to_string()),
-                Err(_) => (),
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
             }
             Err(ClientError::UnexpectedResponse(status))
         }
     }
+
+    /// Returns all metadata field definitions in the Unity Organization.
+    pub async fn list_metadata_definitions(&self) -> Result<Vec<MetadataDefinition>, ClientError> {
+        let mut next: Option<String> = None;
+        let mut definitions: Vec<MetadataDefinition> = Vec::new();
+
+        loop {
+            let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL.to_string();
+            let mut token_values: HashMap<String, String> = HashMap::new();
+            token_values.insert(
+                "organizationId".to_string(),
+                self.organization_id.to_owned(),
+            );
+            let path = strfmt(
+                "/assets/v1/organizations/{organizationId}/templates/fields",
+                &token_values,
+            )
+            .unwrap();
+            url.push_str(path.as_str());
+
+            let mut query = vec![("limit".to_string(), "100".to_string())];
+            if let Some(next) = &next {
+                query.push(("next".to_string(), next.to_owned()));
+            }
+
+            log::trace!("GET {}", url);
+
+            let response = self
+                .http
+                .get(url)
+                .header("cache-control", "no-cache")
+                .timeout(Duration::from_secs(30))
+                .basic_auth(
+                    self.client_id.to_owned(),
+                    Some(self.client_secret.to_owned()),
+                )
+                .query(&query)
+                .send()
+                .await?;
+
+            let status = response.status();
+            if status.is_success() {
+                let content = response.text().await?;
+
+                log::trace!("Response: {}", content);
+
+                let mut response: MetadataDefinitionListResponse = serde_json::from_str(&content)?;
+                definitions.append(&mut response.results);
+
+                next = response.next.filter(|n| !n.is_empty());
+                if next.is_none() {
+                    break;
+                }
+            } else {
+                let content = response.text().await;
+                if let Ok(content) = content {
+                    log::error!("Error: {}", content)
+                }
+                return Err(ClientError::UnexpectedResponse(status));
+            }
+        }
+
+        Ok(definitions)
+    }
+
+    /// Updates the display name and/or the accepted values of a metadata field definition.
+    ///
+    /// Parameters:
+    ///
+    /// * name: the name of the field
+    /// * changes: the properties to be updated
+    pub async fn update_metadata_definition(
+        &self,
+        name: &str,
+        changes: &MetadataDefinitionUpdate,
+    ) -> Result<(), ClientError> {
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert(
+            "organizationId".to_string(),
+            self.organization_id.to_owned(),
+        );
+        token_values.insert("name".to_string(), encode(name).to_string());
+        let path = strfmt(
+            "/assets/v1/organizations/{organizationId}/templates/fields/{name}",
+            &token_values,
+        )
+        .unwrap();
+        url.push_str(path.as_str());
+
+        log::trace!("PATCH {}", url);
+        log::trace!("Request: {:?}", changes);
+
+        let response = self
+            .http
+            .patch(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .json(changes)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let content = response.text().await?;
+
+            log::trace!("Response: {}", content);
+            Ok(())
+        } else {
+            let content = response.text().await;
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
+            }
+            match status {
+                StatusCode::NOT_FOUND => Err(ClientError::NotFound),
+                _ => Err(ClientError::UnexpectedResponse(status)),
+            }
+        }
+    }
+
+    /// Deletes a metadata field definition from the Unity Organization.
+    ///
+    /// Parameters:
+    ///
+    /// * name: the name of the field
+    pub async fn delete_metadata_definition(&self, name: &str) -> Result<(), ClientError> {
+        let mut url: String = UNITY_PRODUCTION_SERVICES_BASE_ORGANIZATION_URL.to_string();
+        let mut token_values: HashMap<String, String> = HashMap::new();
+        token_values.insert(
+            "organizationId".to_string(),
+            self.organization_id.to_owned(),
+        );
+        token_values.insert("name".to_string(), encode(name).to_string());
+        let path = strfmt(
+            "/assets/v1/organizations/{organizationId}/templates/fields/{name}",
+            &token_values,
+        )
+        .unwrap();
+        url.push_str(path.as_str());
+
+        log::trace!("DELETE {}", url);
+
+        let response = self
+            .http
+            .delete(url)
+            .header("cache-control", "no-cache")
+            .timeout(Duration::from_secs(30))
+            .basic_auth(
+                self.client_id.to_owned(),
+                Some(self.client_secret.to_owned()),
+            )
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(())
+        } else {
+            let content = response.text().await;
+            if let Ok(content) = content {
+                log::error!("Error: {}", content)
+            }
+            match status {
+                StatusCode::NOT_FOUND => Err(ClientError::NotFound),
+                _ => Err(ClientError::UnexpectedResponse(status)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn download_path_creates_parent_directories() {
+        let directory = tempfile::tempdir().unwrap();
+
+        let path =
+            Client::download_path(directory.path(), Path::new("textures/albedo/wood.png")).unwrap();
+        assert_eq!(path, directory.path().join("textures/albedo/wood.png"));
+        assert!(directory.path().join("textures/albedo").is_dir());
+
+        let path = Client::download_path(directory.path(), Path::new("part.stl")).unwrap();
+        assert_eq!(path, directory.path().join("part.stl"));
+    }
 }
@@ -1,21 +1,65 @@
 //! Implements program configuration.
-use crate::security::{Keyring, KeyringError};
+use crate::{
+    checksum::ChecksumAlgorithm,
+    security::{Keyring, KeyringError},
+};
 use dirs::config_dir;
 use log;
 use serde::{Deserialize, Serialize};
 use serde_yaml;
 use std::{
+    collections::BTreeMap,
     fs::{self, File},
     io::Write,
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
-pub const DEFAULT_APPLICATION_ID: &'static str = "uamcli";
-pub const DEFAULT_ORGANIZATION_ID: &'static str = "";
-pub const DEFAULT_PROJECT_ID: &'static str = "";
-pub const DEFAULT_ENVIRONMENT_ID: &'static str = "";
-pub const DEFAULT_CONFIGURATION_FILE_NAME: &'static str = "config.yml";
-pub const DEFAULT_CLIENT_SECRET_KEY: &'static str = "client_secret";
+pub const DEFAULT_APPLICATION_ID: &str = "uamcli";
+pub const DEFAULT_ORGANIZATION_ID: &str = "";
+pub const DEFAULT_PROJECT_ID: &str = "";
+pub const DEFAULT_ENVIRONMENT_ID: &str = "";
+pub const DEFAULT_CONFIGURATION_FILE_NAME: &str = "config.yml";
+pub const DEFAULT_CLIENT_SECRET_KEY: &str = "client_secret";
+pub const DEFAULT_PRIMARY_TYPE: &str = "3D Model";
+
+/// Default mapping of file extensions to Unity asset primary types.
+const DEFAULT_PRIMARY_TYPES: [(&str, &str); 28] = [
+    ("fbx", "3D Model"),
+    ("obj", "3D Model"),
+    ("glb", "3D Model"),
+    ("gltf", "3D Model"),
+    ("stl", "3D Model"),
+    ("ply", "3D Model"),
+    ("step", "3D Model"),
+    ("stp", "3D Model"),
+    ("iges", "3D Model"),
+    ("igs", "3D Model"),
+    ("usd", "3D Model"),
+    ("usdz", "3D Model"),
+    ("dae", "3D Model"),
+    ("3ds", "3D Model"),
+    ("png", "2D Asset"),
+    ("jpg", "2D Asset"),
+    ("jpeg", "2D Asset"),
+    ("tga", "2D Asset"),
+    ("tif", "2D Asset"),
+    ("tiff", "2D Asset"),
+    ("exr", "2D Asset"),
+    ("wav", "Audio"),
+    ("mp3", "Audio"),
+    ("ogg", "Audio"),
+    ("mat", "Material"),
+    ("mp4", "Video"),
+    ("mov", "Video"),
+    ("pdf", "Other"),
+];
+
+fn default_primary_types() -> BTreeMap<String, String> {
+    DEFAULT_PRIMARY_TYPES
+        .iter()
+        .map(|(extension, primary_type)| (extension.to_string(), primary_type.to_string()))
+        .collect()
+}
 
 /// A wrapper for all configuration errors.
 #[derive(Debug, thiserror::Error)]
@@ -45,6 +89,10 @@ pub struct Configuration {
     client_id: Option<String>, // Unity Key ID for service account authorization
     #[serde(skip_serializing)]
     client_secret: Option<String>, // Unity Key Secret for service account authentication
+    #[serde(default)]
+    checksum_algorithm: ChecksumAlgorithm, // algorithm used for the user checksum of uploaded files
+    #[serde(default = "default_primary_types")]
+    primary_types: BTreeMap<String, String>, // file extension to asset primary type mapping
 }
 
 impl Default for Configuration {
@@ -73,6 +121,8 @@ impl Configuration {
             project_id,
             client_id,
             client_secret,
+            checksum_algorithm: ChecksumAlgorithm::default(),
+            primary_types: default_primary_types(),
         }
     }
 
@@ -120,6 +170,54 @@ impl Configuration {
         self.client_secret.to_owned()
     }
 
+    pub fn checksum_algorithm(&self) -> ChecksumAlgorithm {
+        self.checksum_algorithm
+    }
+
+    pub fn set_checksum_algorithm(&mut self, checksum_algorithm: ChecksumAlgorithm) {
+        self.checksum_algorithm = checksum_algorithm;
+    }
+
+    pub fn primary_types(&self) -> BTreeMap<String, String> {
+        self.primary_types.clone()
+    }
+
+    /// Maps a file extension to an asset primary type.
+    ///
+    /// Parameters:
+    ///
+    /// * extension: the file extension, with or without the leading dot
+    /// * primary_type: the Unity asset primary type (e.g. "3D Model")
+    pub fn set_primary_type(&mut self, extension: &str, primary_type: String) {
+        self.primary_types
+            .insert(Self::normalize_extension(extension), primary_type);
+    }
+
+    /// Removes the mapping for a file extension.
+    ///
+    /// Parameters:
+    ///
+    /// * extension: the file extension, with or without the leading dot
+    pub fn remove_primary_type(&mut self, extension: &str) {
+        self.primary_types
+            .remove(&Self::normalize_extension(extension));
+    }
+
+    /// Returns the asset primary type mapped to the extension of a file, if any.
+    ///
+    /// Parameters:
+    ///
+    /// * path: path to the file
+    pub fn primary_type_for(&self, path: &Path) -> Option<String> {
+        path.extension()
+            .map(|extension| Self::normalize_extension(&extension.to_string_lossy()))
+            .and_then(|extension| self.primary_types.get(&extension).cloned())
+    }
+
+    fn normalize_extension(extension: &str) -> String {
+        extension.trim_start_matches('.').to_lowercase()
+    }
+
     /// Returns the default configuration file path.
     ///
     /// This path is specific to the underlying OS.
@@ -220,7 +318,7 @@ impl Configuration {
         }
 
         // write to file
-        let file = File::create(&path);
+        let file = File::create(path);
         match file {
             Ok(file) => {
                 let writer: Box<dyn Write> = Box::new(file);
@@ -1,7 +1,11 @@
 //! This crate offers a higher-level client interface to the Unity Asset Manager REST API.
 pub mod api;
+pub mod checksum;
 pub mod cli;
 pub mod client;
 pub mod configuration;
 pub mod model;
+pub mod preview;
 pub mod security;
+pub mod stats;
+pub mod validation;
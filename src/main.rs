@@ -1,4 +1,3 @@
-use pretty_env_logger;
 use std::cell::RefCell;
 use thiserror::Error;
 use uamcli::{
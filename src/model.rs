@@ -1,6 +1,12 @@
 //! Data model structures for interfacing with Unity
-use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, str::FromStr};
+use crate::stats::ModelStats;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::{
+    collections::{HashMap, VecDeque},
+    path::PathBuf,
+    str::FromStr,
+};
 use thiserror::Error;
 
 /// A wrapper for all errors generated when working with asset status.
@@ -14,7 +20,13 @@ impl std::fmt::Display for AssetStatusParseError {
 }
 
 /// Unity Asset status abstraction.
-#[derive(Debug, PartialEq, PartialOrd, Clone, Serialize, Deserialize)]
+///
+/// Statuses not known to this version are kept as `Unknown` with the value reported by Unity,
+/// so that assets can still be read. No transitions are possible from an unknown status.
+///
+/// A status is serialized and displayed with its Unity name (e.g. InReview), so JSON, tables and
+/// messages use the same spelling. Parsing is case-insensitive and also accepts e.g. inreview or in-review.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Clone)]
 pub enum AssetStatus {
     Draft,
     InReview,
@@ -22,22 +34,43 @@ pub enum AssetStatus {
     Published,
     Rejected,
     Withdrawn,
+    Unknown(String),
+}
+
+impl AssetStatus {
+    /// Returns the status name as used by Unity (e.g. "InReview").
+    pub fn name(&self) -> &str {
+        match self {
+            AssetStatus::Draft => "Draft",
+            AssetStatus::InReview => "InReview",
+            AssetStatus::Approved => "Approved",
+            AssetStatus::Published => "Published",
+            AssetStatus::Rejected => "Rejected",
+            AssetStatus::Withdrawn => "Withdrawn",
+            AssetStatus::Unknown(name) => name,
+        }
+    }
+}
+
+impl Serialize for AssetStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for AssetStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        match value.parse() {
+            Ok(status) => Ok(status),
+            Err(_) => Ok(AssetStatus::Unknown(value)),
+        }
+    }
 }
 
 impl std::fmt::Display for AssetStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                AssetStatus::Draft => "draft",
-                AssetStatus::InReview => "inreview",
-                AssetStatus::Approved => "approved",
-                AssetStatus::Published => "published",
-                AssetStatus::Rejected => "rejected",
-                AssetStatus::Withdrawn => "withdrawn",
-            }
-        )
+        write!(f, "{}", self.name())
     }
 }
 
@@ -45,7 +78,7 @@ impl FromStr for AssetStatus {
     type Err = AssetStatusParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let value = s.to_lowercase();
+        let value = s.to_lowercase().replace([' ', '_', '-'], "");
         match value.as_ref() {
             "draft" => Ok(AssetStatus::Draft),
             "inreview" => Ok(AssetStatus::InReview),
@@ -58,6 +91,298 @@ impl FromStr for AssetStatus {
     }
 }
 
+impl AssetStatus {
+    /// Returns the statuses that can be set directly from this status.
+    ///
+    /// The workflow is: Draft -> InReview -> Approved -> Published -> Withdrawn.
+    /// A review can end with Rejected, after which the asset goes back to Draft.
+    pub fn transitions(&self) -> Vec<AssetStatus> {
+        match self {
+            AssetStatus::Draft => vec![AssetStatus::InReview],
+            AssetStatus::InReview => vec![AssetStatus::Approved, AssetStatus::Rejected],
+            AssetStatus::Approved => vec![AssetStatus::Published],
+            AssetStatus::Published => vec![AssetStatus::Withdrawn],
+            AssetStatus::Rejected => vec![AssetStatus::Draft],
+            AssetStatus::Withdrawn | AssetStatus::Unknown(_) => vec![],
+        }
+    }
+
+    /// Returns the allowed transitions of all statuses, e.g. "Draft -> InReview, InReview -> Approved|Rejected".
+    pub fn workflow() -> String {
+        [
+            AssetStatus::Draft,
+            AssetStatus::InReview,
+            AssetStatus::Approved,
+            AssetStatus::Published,
+            AssetStatus::Rejected,
+            AssetStatus::Withdrawn,
+        ]
+        .iter()
+        .filter_map(|status| {
+            let transitions = status.transitions();
+            (!transitions.is_empty()).then(|| {
+                let targets: Vec<String> = transitions.iter().map(|t| t.to_string()).collect();
+                format!("{} -> {}", status, targets.join("|"))
+            })
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+    }
+
+    /// Returns the shortest sequence of statuses to set in order to get from this status
+    /// to the target status. The sequence does not include this status and it is empty if
+    /// the target is the same as this status. Returns None if the target cannot be reached.
+    ///
+    /// Parameters:
+    ///
+    /// * target - the desired status
+    pub fn path_to(&self, target: &AssetStatus) -> Option<Vec<AssetStatus>> {
+        let mut previous: HashMap<AssetStatus, AssetStatus> = HashMap::new();
+        let mut queue: VecDeque<AssetStatus> = VecDeque::from([self.clone()]);
+
+        while let Some(status) = queue.pop_front() {
+            if &status == target {
+                let mut path = vec![];
+                let mut current = status;
+                while &current != self {
+                    let next = previous[&current].clone();
+                    path.push(current);
+                    current = next;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for next in status.transitions() {
+                if &next != self && !previous.contains_key(&next) {
+                    previous.insert(next.clone(), status.clone());
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Unity Asset primary type abstraction.
+///
+/// Types not known to this version are kept as `Unknown` with the value reported by Unity.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Clone)]
+pub enum AssetType {
+    Asset2D,
+    Model3D,
+    Animation,
+    Audio,
+    Material,
+    Prefab,
+    Script,
+    Video,
+    UnityEditor,
+    Other,
+    Unknown(String),
+}
+
+impl std::fmt::Display for AssetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                AssetType::Asset2D => "2D Asset",
+                AssetType::Model3D => "3D Model",
+                AssetType::Animation => "Animation",
+                AssetType::Audio => "Audio",
+                AssetType::Material => "Material",
+                AssetType::Prefab => "Prefab",
+                AssetType::Script => "Script",
+                AssetType::Video => "Video",
+                AssetType::UnityEditor => "Unity Editor",
+                AssetType::Other => "Other",
+                AssetType::Unknown(name) => name,
+            }
+        )
+    }
+}
+
+impl From<&str> for AssetType {
+    fn from(s: &str) -> Self {
+        let value = s.to_lowercase().replace([' ', '_', '-'], "");
+        match value.as_ref() {
+            "2dasset" => AssetType::Asset2D,
+            "3dmodel" => AssetType::Model3D,
+            "animation" => AssetType::Animation,
+            "audio" => AssetType::Audio,
+            "material" => AssetType::Material,
+            "prefab" => AssetType::Prefab,
+            "script" => AssetType::Script,
+            "video" => AssetType::Video,
+            "unityeditor" => AssetType::UnityEditor,
+            "other" => AssetType::Other,
+            _ => AssetType::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl Serialize for AssetType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AssetType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(AssetType::from(value.as_str()))
+    }
+}
+
+/// Result of changing the status of an asset.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct StatusTransition {
+    pub asset: AssetIdentity,
+    pub from: AssetStatus,
+    /// The statuses set in order. The last one is the target status.
+    pub path: Vec<AssetStatus>,
+}
+
+/// Outcome of changing the status of one of many assets.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StatusTransitionStatus {
+    /// The status was changed.
+    Changed,
+    /// The asset already had the target status.
+    Unchanged,
+    /// Dry run only. The status would be changed.
+    Pending,
+    /// The status could not be changed. See the error.
+    Failed,
+}
+
+/// Result of changing the status of one of many assets.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct StatusTransitionResult {
+    pub asset: AssetIdentity,
+    pub name: String,
+    /// The status before the change.
+    pub from: AssetStatus,
+    /// The statuses set (or to be set) in order.
+    pub path: Vec<AssetStatus>,
+    pub status: StatusTransitionStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// A wrapper for all errors generated when parsing a metadata upload mode.
+#[derive(Debug, Error)]
+pub struct MetadataModeParseError(String);
+
+impl std::fmt::Display for MetadataModeParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Failed to parse metadata mode value of {}", self.0)
+    }
+}
+
+/// Determines how uploaded metadata is combined with the existing asset metadata.
+#[derive(Debug, Default, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum MetadataMode {
+    /// Existing fields are kept. Uploaded values override them. Empty values are ignored.
+    #[default]
+    Merge,
+    /// Existing fields are discarded and only the uploaded values are kept.
+    Replace,
+    /// Like merge, but fields with empty values are deleted from the asset.
+    Patch,
+}
+
+impl std::fmt::Display for MetadataMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                MetadataMode::Merge => "merge",
+                MetadataMode::Replace => "replace",
+                MetadataMode::Patch => "patch",
+            }
+        )
+    }
+}
+
+impl FromStr for MetadataMode {
+    type Err = MetadataModeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.to_lowercase();
+        match value.as_ref() {
+            "merge" => Ok(MetadataMode::Merge),
+            "replace" => Ok(MetadataMode::Replace),
+            "patch" => Ok(MetadataMode::Patch),
+            _ => Err(MetadataModeParseError(value.to_string())),
+        }
+    }
+}
+
+/// A wrapper for all errors generated when parsing a metadata file format.
+#[derive(Debug, Error)]
+pub struct MetadataFormatParseError(String);
+
+impl std::fmt::Display for MetadataFormatParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Failed to parse metadata format value of {}", self.0)
+    }
+}
+
+/// Supported metadata file formats.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum MetadataFormat {
+    /// Two columns with a header line: Name, Value
+    Csv,
+    /// An object with field names as keys
+    Json,
+    /// An object with field names as keys
+    Yaml,
+}
+
+impl MetadataFormat {
+    /// Detects the format from the file extension.
+    pub fn from_path(path: &std::path::Path) -> Option<MetadataFormat> {
+        path.extension()
+            .and_then(|e| e.to_string_lossy().parse().ok())
+    }
+}
+
+impl std::fmt::Display for MetadataFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                MetadataFormat::Csv => "csv",
+                MetadataFormat::Json => "json",
+                MetadataFormat::Yaml => "yaml",
+            }
+        )
+    }
+}
+
+impl FromStr for MetadataFormat {
+    type Err = MetadataFormatParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.to_lowercase();
+        match value.as_ref() {
+            "csv" => Ok(MetadataFormat::Csv),
+            "json" => Ok(MetadataFormat::Json),
+            "yaml" | "yml" => Ok(MetadataFormat::Yaml),
+            _ => Err(MetadataFormatParseError(value.to_string())),
+        }
+    }
+}
+
+/// Version keyword that refers to the latest version of an asset. See AssetVersion::latest.
+pub const LATEST_VERSION: &str = "latest";
+
 /// Asset identity abstraction.
 #[derive(Debug, PartialEq, PartialOrd, Clone, Serialize, Deserialize)]
 pub struct AssetIdentity {
@@ -85,6 +410,107 @@ impl AssetIdentity {
     pub fn set_version(&mut self, version: String) {
         self.version = version.to_owned();
     }
+
+    /// Returns true if the version is the latest keyword instead of a version ID.
+    pub fn is_latest(&self) -> bool {
+        self.version.eq_ignore_ascii_case(LATEST_VERSION)
+    }
+}
+
+/// Unity asset version abstraction.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct AssetVersion {
+    #[serde(rename = "assetId")]
+    asset_id: String,
+    #[serde(rename = "assetVersion")]
+    version: String,
+    /// Sequential number assigned when the version is frozen.
+    #[serde(rename = "versionNumber", default)]
+    version_number: Option<u64>,
+    #[serde(rename = "isFrozen", default)]
+    is_frozen: bool,
+    #[serde(rename = "parentAssetVersion", default)]
+    parent_version: Option<String>,
+    #[serde(rename = "parentVersionNumber", default)]
+    parent_version_number: Option<u64>,
+    #[serde(rename = "changelog", default)]
+    changelog: Option<String>,
+    #[serde(rename = "status", default)]
+    status: Option<String>,
+    #[serde(rename = "created", default)]
+    created: Option<String>,
+    #[serde(rename = "updated", default)]
+    updated: Option<String>,
+}
+
+impl AssetVersion {
+    pub fn identity(&self) -> AssetIdentity {
+        AssetIdentity::new(self.asset_id.to_owned(), self.version.to_owned())
+    }
+
+    pub fn version(&self) -> String {
+        self.version.to_owned()
+    }
+
+    pub fn version_number(&self) -> Option<u64> {
+        self.version_number
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.is_frozen
+    }
+
+    pub fn parent_version(&self) -> Option<String> {
+        self.parent_version.to_owned()
+    }
+
+    pub fn parent_version_number(&self) -> Option<u64> {
+        self.parent_version_number
+    }
+
+    pub fn changelog(&self) -> Option<String> {
+        self.changelog.to_owned()
+    }
+
+    pub fn status(&self) -> Option<String> {
+        self.status.to_owned()
+    }
+
+    pub fn created(&self) -> Option<String> {
+        self.created.to_owned()
+    }
+
+    pub fn updated(&self) -> Option<String> {
+        self.updated.to_owned()
+    }
+
+    /// Returns the latest of the versions.
+    ///
+    /// A version that is not frozen yet is newer than all frozen versions. Among versions
+    /// that are not frozen the most recently created one is returned, among frozen versions
+    /// the one with the highest version number.
+    pub fn latest(versions: &[AssetVersion]) -> Option<&AssetVersion> {
+        versions.iter().max_by(|a, b| a.cmp_recency(b))
+    }
+
+    /// Sorts the versions from the latest to the oldest, in the order used by latest.
+    pub fn sort_latest_first(versions: &mut [AssetVersion]) {
+        versions.sort_by(|a, b| b.cmp_recency(a));
+    }
+
+    /// Compares two versions by how recent they are. See latest.
+    fn cmp_recency(&self, other: &AssetVersion) -> std::cmp::Ordering {
+        (
+            !self.is_frozen,
+            self.version_number,
+            self.created.as_deref(),
+        )
+            .cmp(&(
+                !other.is_frozen,
+                other.version_number,
+                other.created.as_deref(),
+            ))
+    }
 }
 
 /// Unity Dataset abstraction.
@@ -96,6 +522,14 @@ pub struct Dataset {
     name: String,
     #[serde(rename = "primaryType")]
     primary_type: Option<String>,
+    #[serde(
+        rename = "description",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    description: Option<String>,
+    #[serde(rename = "tags", default, skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
 }
 
 impl Dataset {
@@ -104,6 +538,8 @@ impl Dataset {
             id,
             name,
             primary_type,
+            description: None,
+            tags: None,
         }
     }
 
@@ -123,20 +559,178 @@ impl Dataset {
         self.name = name.to_owned();
     }
 
+    pub fn primary_type(&self) -> Option<String> {
+        self.primary_type.to_owned()
+    }
+
     pub fn set_primary_type(&mut self, primary_type: Option<String>) {
         self.primary_type = primary_type.to_owned();
     }
+
+    pub fn description(&self) -> Option<String> {
+        self.description.to_owned()
+    }
+
+    pub fn set_description(&mut self, description: Option<String>) {
+        self.description = description.to_owned();
+    }
+
+    pub fn tags(&self) -> Option<Vec<String>> {
+        self.tags.to_owned()
+    }
+
+    pub fn set_tags(&mut self, tags: Option<Vec<String>>) {
+        self.tags = tags.to_owned();
+    }
+}
+
+/// Status of a Unity transformation (a server-side job such as thumbnail generation).
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub enum TransformationStatus {
+    Pending,
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+    #[serde(other)]
+    Unknown,
+}
+
+impl TransformationStatus {
+    /// Returns true if the transformation will not change its status anymore.
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            TransformationStatus::Succeeded
+                | TransformationStatus::Failed
+                | TransformationStatus::Cancelled
+        )
+    }
+}
+
+impl std::fmt::Display for TransformationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TransformationStatus::Pending => "pending",
+                TransformationStatus::Running => "running",
+                TransformationStatus::Succeeded => "succeeded",
+                TransformationStatus::Failed => "failed",
+                TransformationStatus::Cancelled => "cancelled",
+                TransformationStatus::Unknown => "unknown",
+            }
+        )
+    }
+}
+
+/// Unity transformation abstraction.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Transformation {
+    id: String,
+    #[serde(rename = "workflowType", default)]
+    workflow_type: Option<String>,
+    status: TransformationStatus,
+    #[serde(rename = "errorMessage", default)]
+    error_message: Option<String>,
+    #[serde(rename = "inputFiles", default)]
+    input_files: Vec<String>,
+    #[serde(rename = "outputDatasetId", default)]
+    output_dataset_id: Option<String>,
+    #[serde(rename = "datasetId", default)]
+    dataset_id: Option<String>,
+    #[serde(rename = "createdOn", default)]
+    created_on: Option<String>,
+    #[serde(rename = "updatedAt", default)]
+    updated_at: Option<String>,
+}
+
+impl Transformation {
+    pub fn id(&self) -> String {
+        self.id.to_owned()
+    }
+
+    pub fn workflow_type(&self) -> Option<String> {
+        self.workflow_type.to_owned()
+    }
+
+    pub fn status(&self) -> TransformationStatus {
+        self.status.to_owned()
+    }
+
+    pub fn error_message(&self) -> Option<String> {
+        self.error_message.to_owned()
+    }
+
+    pub fn input_files(&self) -> Vec<String> {
+        self.input_files.clone()
+    }
+
+    pub fn output_dataset_id(&self) -> Option<String> {
+        self.output_dataset_id.to_owned()
+    }
+
+    pub fn dataset_id(&self) -> Option<String> {
+        self.dataset_id.to_owned()
+    }
+
+    pub fn created_on(&self) -> Option<String> {
+        self.created_on.to_owned()
+    }
+
+    pub fn updated_at(&self) -> Option<String> {
+        self.updated_at.to_owned()
+    }
 }
 
+/// Outcome of generating the thumbnail for one asset.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThumbnailGenerationStatus {
+    /// The asset already has a thumbnail. Nothing was done.
+    Skipped,
+    /// The transformation was started, but not awaited.
+    Started,
+    /// The transformation completed.
+    Succeeded,
+    /// The transformation could not be started or it failed. See the error.
+    Failed,
+    /// The transformation did not finish before the timeout.
+    TimedOut,
+}
+
+/// Result of generating the thumbnail for one asset.
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
-pub struct ThumbnailGenerationRequest {
+pub struct ThumbnailGenerationResult {
+    pub asset: AssetIdentity,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transformation_id: Option<String>,
+    pub status: ThumbnailGenerationStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Request body for starting a transformation workflow.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct TransformationRequest {
     #[serde(rename = "inputFiles")]
     input_files: Vec<String>,
+    #[serde(
+        rename = "extraParameters",
+        skip_serializing_if = "HashMap::is_empty",
+        default
+    )]
+    extra_parameters: HashMap<String, Value>,
 }
 
-impl ThumbnailGenerationRequest {
-    pub fn new(input_files: Vec<String>) -> Self {
-        Self { input_files }
+impl TransformationRequest {
+    pub fn new(input_files: Vec<String>, extra_parameters: HashMap<String, Value>) -> Self {
+        Self {
+            input_files,
+            extra_parameters,
+        }
     }
 }
 
@@ -149,17 +743,18 @@ pub struct Asset {
     tags: Option<Vec<String>>,
     system_tags: Option<Vec<String>>,
     labels: Vec<String>,
-    primary_type: String,
-    status: String,
+    primary_type: AssetType,
+    status: AssetStatus,
     source_project_id: String,
     project_ids: Vec<String>,
     preview_file: Option<String>,
     preview_file_dataset_id: Option<String>,
     datasets: Option<Vec<Dataset>>,
-    metadata: Option<Option<::std::collections::HashMap<String, String>>>,
+    metadata: Option<Option<::std::collections::HashMap<String, Value>>>,
 }
 
 impl Asset {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         identity: AssetIdentity,
         name: String,
@@ -167,14 +762,14 @@ impl Asset {
         tags: Option<Vec<String>>,
         system_tags: Option<Vec<String>>,
         labels: Vec<String>,
-        primary_type: String,
-        status: String,
+        primary_type: AssetType,
+        status: AssetStatus,
         source_project_id: String,
         project_ids: Vec<String>,
         preview_file: Option<String>,
         preview_file_dataset_id: Option<String>,
         datasets: Option<Vec<Dataset>>,
-        metadata: Option<Option<::std::collections::HashMap<String, String>>>,
+        metadata: Option<Option<::std::collections::HashMap<String, Value>>>,
     ) -> Self {
         Asset {
             identity,
@@ -242,19 +837,19 @@ impl Asset {
         self.labels = labels.clone();
     }
 
-    pub fn primary_type(&self) -> String {
+    pub fn primary_type(&self) -> AssetType {
         self.primary_type.to_owned()
     }
 
-    pub fn set_primary_type(&mut self, primary_type: String) {
+    pub fn set_primary_type(&mut self, primary_type: AssetType) {
         self.primary_type = primary_type.to_owned();
     }
 
-    pub fn status(&self) -> String {
+    pub fn status(&self) -> AssetStatus {
         self.status.to_owned()
     }
 
-    pub fn set_status(&mut self, status: String) {
+    pub fn set_status(&mut self, status: AssetStatus) {
         self.status = status.to_owned();
     }
 
@@ -298,11 +893,11 @@ impl Asset {
         self.datasets = datasets.clone();
     }
 
-    pub fn metadata(&self) -> Option<Option<HashMap<String, String>>> {
+    pub fn metadata(&self) -> Option<Option<HashMap<String, Value>>> {
         self.metadata.clone()
     }
 
-    pub fn set_metadata(&mut self, metadata: Option<Option<HashMap<String, String>>>) {
+    pub fn set_metadata(&mut self, metadata: Option<Option<HashMap<String, Value>>>) {
         self.metadata = metadata.clone();
     }
 }
@@ -316,8 +911,82 @@ pub struct MetadataEntry {
     pub value: Option<String>,
 }
 
+/// A wrapper for all errors generated when parsing a metadata field type.
+#[derive(Debug, Error)]
+pub struct MetadataFieldTypeParseError(String);
+
+impl std::fmt::Display for MetadataFieldTypeParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Failed to parse metadata field type value of {}", self.0)
+    }
+}
+
+/// Unity metadata field types.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MetadataFieldType {
+    Text,
+    Number,
+    Boolean,
+    Timestamp,
+    Url,
+    User,
+    SingleSelect,
+    MultiSelect,
+}
+
+impl std::fmt::Display for MetadataFieldType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                MetadataFieldType::Text => "text",
+                MetadataFieldType::Number => "number",
+                MetadataFieldType::Boolean => "boolean",
+                MetadataFieldType::Timestamp => "timestamp",
+                MetadataFieldType::Url => "url",
+                MetadataFieldType::User => "user",
+                MetadataFieldType::SingleSelect => "single-select",
+                MetadataFieldType::MultiSelect => "multi-select",
+            }
+        )
+    }
+}
+
+impl FromStr for MetadataFieldType {
+    type Err = MetadataFieldTypeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.to_lowercase();
+        match value.as_ref() {
+            "text" => Ok(MetadataFieldType::Text),
+            "number" => Ok(MetadataFieldType::Number),
+            "boolean" => Ok(MetadataFieldType::Boolean),
+            "timestamp" => Ok(MetadataFieldType::Timestamp),
+            "url" => Ok(MetadataFieldType::Url),
+            "user" => Ok(MetadataFieldType::User),
+            "single-select" | "selection" => Ok(MetadataFieldType::SingleSelect),
+            "multi-select" => Ok(MetadataFieldType::MultiSelect),
+            _ => Err(MetadataFieldTypeParseError(value.to_string())),
+        }
+    }
+}
+
+/// Error returned when a metadata value does not match the field definition.
+#[derive(Debug, Error)]
+#[error("Invalid value {value} for {field_type} metadata field {field}")]
+pub struct MetadataValueError {
+    pub field: String,
+    pub field_type: MetadataFieldType,
+    pub value: Value,
+}
+
 /// Unity metadata field definition abstraction.
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// Unity represents both single and multi-select fields with the type 'selection'
+/// and distinguishes them with the multiselection flag.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct MetadataDefinition {
     #[serde(rename = "name")]
     pub name: String,
@@ -325,4 +994,949 @@ pub struct MetadataDefinition {
     pub display_name: String,
     #[serde(rename = "type")]
     pub value_type: String,
+    #[serde(rename = "multiselection", skip_serializing_if = "Option::is_none")]
+    pub multiselection: Option<bool>,
+    #[serde(rename = "acceptedValues", skip_serializing_if = "Option::is_none")]
+    pub accepted_values: Option<Vec<String>>,
+}
+
+impl MetadataDefinition {
+    pub fn new(
+        name: String,
+        display_name: String,
+        field_type: MetadataFieldType,
+        accepted_values: Option<Vec<String>>,
+    ) -> Self {
+        let (value_type, multiselection) = match field_type {
+            MetadataFieldType::SingleSelect => ("selection".to_string(), Some(false)),
+            MetadataFieldType::MultiSelect => ("selection".to_string(), Some(true)),
+            _ => (field_type.to_string(), None),
+        };
+
+        Self {
+            name,
+            display_name,
+            value_type,
+            multiselection,
+            accepted_values,
+        }
+    }
+
+    /// Returns the field type. Unknown types are treated as text.
+    pub fn field_type(&self) -> MetadataFieldType {
+        match self.value_type.as_str() {
+            "selection" if self.multiselection.unwrap_or(false) => MetadataFieldType::MultiSelect,
+            value_type => value_type.parse().unwrap_or(MetadataFieldType::Text),
+        }
+    }
+
+    /// Validates a value against this definition and converts it to the representation
+    /// expected by Unity. Strings are accepted for all types and parsed as needed, so that
+    /// values read from CSV files can be used for typed fields.
+    ///
+    /// Parameters:
+    ///
+    /// * value - the value to coerce
+    pub fn coerce(&self, value: &Value) -> Result<Value, MetadataValueError> {
+        let field_type = self.field_type();
+        let invalid = || MetadataValueError {
+            field: self.name.to_owned(),
+            field_type,
+            value: value.clone(),
+        };
+
+        match field_type {
+            MetadataFieldType::Text | MetadataFieldType::User => match value {
+                Value::String(_) => Ok(value.clone()),
+                Value::Number(_) | Value::Bool(_) => Ok(Value::String(value.to_string())),
+                _ => Err(invalid()),
+            },
+            MetadataFieldType::Number => match value {
+                Value::Number(_) => Ok(value.clone()),
+                Value::String(s) => {
+                    let s = s.trim();
+                    if let Ok(n) = s.parse::<i64>() {
+                        Ok(Value::from(n))
+                    } else {
+                        s.parse::<f64>()
+                            .ok()
+                            .and_then(serde_json::Number::from_f64)
+                            .map(Value::Number)
+                            .ok_or_else(invalid)
+                    }
+                }
+                _ => Err(invalid()),
+            },
+            MetadataFieldType::Boolean => match value {
+                Value::Bool(_) => Ok(value.clone()),
+                Value::String(s) => match s.trim().to_lowercase().as_str() {
+                    "true" | "yes" | "1" => Ok(Value::Bool(true)),
+                    "false" | "no" | "0" => Ok(Value::Bool(false)),
+                    _ => Err(invalid()),
+                },
+                _ => Err(invalid()),
+            },
+            MetadataFieldType::Timestamp => match value {
+                Value::String(s) => {
+                    let s = s.trim();
+                    if let Ok(timestamp) = chrono::DateTime::parse_from_rfc3339(s) {
+                        Ok(Value::String(timestamp.to_rfc3339()))
+                    } else if let Ok(date) = chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+                        Ok(Value::String(format!("{}T00:00:00+00:00", date)))
+                    } else {
+                        Err(invalid())
+                    }
+                }
+                _ => Err(invalid()),
+            },
+            MetadataFieldType::Url => match value {
+                Value::String(s) if url::Url::parse(s.trim()).is_ok() => {
+                    Ok(Value::String(s.trim().to_owned()))
+                }
+                _ => Err(invalid()),
+            },
+            MetadataFieldType::SingleSelect => match value {
+                Value::String(s) if self.accepts(s) => Ok(value.clone()),
+                _ => Err(invalid()),
+            },
+            MetadataFieldType::MultiSelect => {
+                let values: Vec<String> = match value {
+                    Value::Array(values) => values
+                        .iter()
+                        .map(|v| v.as_str().map(|s| s.to_owned()).ok_or_else(invalid))
+                        .collect::<Result<_, _>>()?,
+                    Value::String(s) => match serde_json::from_str::<Vec<String>>(s) {
+                        Ok(values) => values,
+                        Err(_) => s.split(',').map(|v| v.trim().to_owned()).collect(),
+                    },
+                    _ => return Err(invalid()),
+                };
+                if values.iter().all(|v| self.accepts(v)) {
+                    Ok(Value::from(values))
+                } else {
+                    Err(invalid())
+                }
+            }
+        }
+    }
+
+    /// Returns true if the value is one of the accepted values or if there is no restriction.
+    fn accepts(&self, value: &str) -> bool {
+        match &self.accepted_values {
+            Some(accepted_values) => accepted_values.iter().any(|v| v == value),
+            None => true,
+        }
+    }
+}
+
+/// Unity file abstraction.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct AssetFile {
+    path: String,
+    size: Option<u64>,
+    user_checksum: Option<String>,
+    status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    metadata: Option<HashMap<String, Value>>,
+    /// IDs of the datasets containing the file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    dataset_ids: Vec<String>,
+}
+
+impl AssetFile {
+    pub fn new(
+        path: String,
+        size: Option<u64>,
+        user_checksum: Option<String>,
+        status: Option<String>,
+    ) -> Self {
+        Self {
+            path,
+            size,
+            user_checksum,
+            status,
+            description: None,
+            tags: None,
+            metadata: None,
+            dataset_ids: vec![],
+        }
+    }
+
+    pub fn path(&self) -> String {
+        self.path.to_owned()
+    }
+
+    pub fn set_path(&mut self, path: String) {
+        self.path = path;
+    }
+
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+
+    pub fn set_size(&mut self, size: Option<u64>) {
+        self.size = size;
+    }
+
+    pub fn user_checksum(&self) -> Option<String> {
+        self.user_checksum.to_owned()
+    }
+
+    pub fn set_user_checksum(&mut self, user_checksum: Option<String>) {
+        self.user_checksum = user_checksum;
+    }
+
+    pub fn status(&self) -> Option<String> {
+        self.status.to_owned()
+    }
+
+    pub fn set_status(&mut self, status: Option<String>) {
+        self.status = status;
+    }
+
+    pub fn description(&self) -> Option<String> {
+        self.description.to_owned()
+    }
+
+    pub fn set_description(&mut self, description: Option<String>) {
+        self.description = description;
+    }
+
+    pub fn tags(&self) -> Option<Vec<String>> {
+        self.tags.to_owned()
+    }
+
+    pub fn set_tags(&mut self, tags: Option<Vec<String>>) {
+        self.tags = tags;
+    }
+
+    pub fn metadata(&self) -> Option<HashMap<String, Value>> {
+        self.metadata.to_owned()
+    }
+
+    pub fn set_metadata(&mut self, metadata: Option<HashMap<String, Value>>) {
+        self.metadata = metadata;
+    }
+
+    pub fn dataset_ids(&self) -> Vec<String> {
+        self.dataset_ids.to_owned()
+    }
+
+    pub fn set_dataset_ids(&mut self, dataset_ids: Vec<String>) {
+        self.dataset_ids = dataset_ids;
+    }
+}
+
+/// Changes to be sent to Unity for an existing file. Fields set to None are left unchanged.
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+pub struct AssetFileUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "tags")]
+    pub tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "metadata")]
+    pub metadata: Option<HashMap<String, Value>>,
+}
+
+impl AssetFileUpdate {
+    /// Returns true if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.description.is_none() && self.tags.is_none() && self.metadata.is_none()
+    }
+}
+
+/// Result of updating a single file.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct AssetFileUpdateResult {
+    pub asset: AssetIdentity,
+    pub dataset_id: String,
+    pub path: String,
+    pub changes: AssetFileUpdate,
+    pub updated: bool,
+}
+
+/// Outcome of comparing a local file against its record in Unity.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum VerificationStatus {
+    /// Size and checksum match.
+    Match,
+    /// The sizes match, but the remote file has no user checksum to compare against.
+    NoChecksum,
+    SizeMismatch,
+    ChecksumMismatch,
+    /// The local file has no counterpart in the asset.
+    MissingRemote,
+    /// The asset file has no local counterpart.
+    MissingLocal,
+}
+
+/// Result of the integrity verification for a single file.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct FileVerification {
+    pub path: String,
+    pub local_size: Option<u64>,
+    pub remote_size: Option<u64>,
+    pub local_checksum: Option<String>,
+    pub remote_checksum: Option<String>,
+    pub status: VerificationStatus,
+}
+
+/// Action taken for a local file when uploading into an existing asset.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum UploadStatus {
+    /// The file did not exist in the asset and was uploaded.
+    Uploaded,
+    /// The file existed with different content and was replaced.
+    Replaced,
+    /// The file existed with the same size and checksum and was not uploaded again.
+    Skipped,
+}
+
+/// Result of uploading a single file into an existing asset.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct FileUploadResult {
+    pub path: String,
+    pub status: UploadStatus,
+}
+
+/// Result of uploading the differences of a local directory into a new asset version.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct LocalDiffApplyResult {
+    pub diff: AssetDiff,
+    /// The new version. None if there was nothing to upload.
+    pub version: Option<AssetVersion>,
+    pub uploads: Vec<FileUploadResult>,
+}
+
+/// A file that shares its content with files in other assets.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct DuplicateFile {
+    pub asset: AssetIdentity,
+    pub asset_name: String,
+    pub path: String,
+}
+
+/// A group of asset files with identical content hashes.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct DuplicateGroup {
+    pub checksum: String,
+    pub size: Option<u64>,
+    pub files: Vec<DuplicateFile>,
+}
+
+/// Changes to be sent to Unity for an existing asset. Fields set to None are left unchanged.
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+pub struct AssetUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "name")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "tags")]
+    pub tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "primaryType")]
+    pub primary_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "previewFile")]
+    pub preview_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "previewFileDatasetId")]
+    pub preview_file_dataset_id: Option<String>,
+}
+
+impl AssetUpdate {
+    /// Returns true if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.description.is_none()
+            && self.tags.is_none()
+            && self.primary_type.is_none()
+            && self.preview_file.is_none()
+            && self.preview_file_dataset_id.is_none()
+    }
+}
+
+/// Changes to be sent to Unity for an existing dataset. Fields set to None are left unchanged.
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+pub struct DatasetUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "name")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "tags")]
+    pub tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "primaryType")]
+    pub primary_type: Option<String>,
+}
+
+impl DatasetUpdate {
+    /// Returns true if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.description.is_none()
+            && self.tags.is_none()
+            && self.primary_type.is_none()
+    }
+}
+
+/// Result of updating a dataset.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct DatasetUpdateResult {
+    pub asset: AssetIdentity,
+    pub dataset_id: String,
+    pub changes: DatasetUpdate,
+    /// True if the dataset was set as the preview dataset of the asset.
+    pub preview: bool,
+    pub updated: bool,
+}
+
+/// Result of rendering the preview image of an asset.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct PreviewRenderResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset: Option<AssetIdentity>,
+    /// The geometry file that was rendered.
+    pub source: String,
+    /// Number of triangles rendered.
+    pub triangles: usize,
+    /// The local PNG file. None if a temporary file was used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<PathBuf>,
+    /// The dataset the image was uploaded to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dataset_id: Option<String>,
+    /// The path of the uploaded image in the dataset, which is set as the asset preview.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview_file: Option<String>,
+}
+
+/// Severity of a problem found while validating the data files.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ValidationSeverity {
+    /// The asset would be broken. Nothing is uploaded.
+    Error,
+    /// The file is uploaded, but it may not be usable.
+    Warning,
+}
+
+impl std::fmt::Display for ValidationSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ValidationSeverity::Error => "error",
+                ValidationSeverity::Warning => "warning",
+            }
+        )
+    }
+}
+
+/// A problem found in a data file.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    pub file: String,
+    pub severity: ValidationSeverity,
+    pub message: String,
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}: {}", self.severity, self.file, self.message)
+    }
+}
+
+/// Result of validating the data files before upload.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ValidationReport {
+    /// The files to be uploaded, including the referenced files that were added.
+    pub files: Vec<PathBuf>,
+    /// The referenced files that were added to the data files.
+    pub included: Vec<PathBuf>,
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Returns true if no errors were found. Warnings are allowed.
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    /// Returns the issues with the error severity.
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|i| i.severity == ValidationSeverity::Error)
+    }
+}
+
+/// Result of extracting the 3D model statistics of an asset.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ModelAnalysisResult {
+    pub asset: AssetIdentity,
+    pub stats: ModelStats,
+    /// The metadata fields that were (or, in a dry run, would be) changed.
+    pub changes: Vec<MetadataChange>,
+}
+
+/// Result of updating a single asset.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct AssetUpdateResult {
+    pub asset: AssetIdentity,
+    pub changes: AssetUpdate,
+    pub updated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// A change to a single metadata field. A missing value means the field does not exist.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct MetadataChange {
+    pub key: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+/// How a file differs between two states of an asset.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FileChangeStatus {
+    /// The file exists only in the newer state.
+    Added,
+    /// The file exists only in the older state.
+    Removed,
+    /// The size or the checksum differ.
+    Modified,
+}
+
+impl std::fmt::Display for FileChangeStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FileChangeStatus::Added => write!(f, "added"),
+            FileChangeStatus::Removed => write!(f, "removed"),
+            FileChangeStatus::Modified => write!(f, "modified"),
+        }
+    }
+}
+
+/// A difference of a single file. Sizes and checksums are missing where the file does not exist.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct FileChange {
+    /// The name of the dataset containing the file.
+    pub dataset: String,
+    pub path: String,
+    pub status: FileChangeStatus,
+    pub size_before: Option<u64>,
+    pub size_after: Option<u64>,
+    pub checksum_before: Option<String>,
+    pub checksum_after: Option<String>,
+}
+
+/// Differences between two versions of an asset, or between an asset version and a local directory.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct AssetDiff {
+    pub asset_id: String,
+    pub from: String,
+    /// The newer version, or the local directory.
+    pub to: String,
+    /// Changes of the name, description, tags, status and primary type.
+    pub fields: Vec<MetadataChange>,
+    pub metadata: Vec<MetadataChange>,
+    /// Names of the datasets that exist only in the newer version.
+    pub datasets_added: Vec<String>,
+    /// Names of the datasets that exist only in the older version.
+    pub datasets_removed: Vec<String>,
+    pub files: Vec<FileChange>,
+}
+
+impl AssetDiff {
+    /// Returns true if there are no differences.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+            && self.metadata.is_empty()
+            && self.datasets_added.is_empty()
+            && self.datasets_removed.is_empty()
+            && self.files.is_empty()
+    }
+}
+
+/// Outcome of importing the metadata of a single manifest row.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MetadataImportStatus {
+    /// The metadata was changed.
+    Updated,
+    /// The metadata already matched the row.
+    Unchanged,
+    /// Dry run only. The metadata would be changed.
+    Pending,
+    /// The row could not be applied. See the error.
+    Failed,
+}
+
+/// Result of importing the metadata of a single manifest row.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct MetadataImportResult {
+    /// Row number in the manifest, including the header line.
+    pub row: usize,
+    pub asset: Option<AssetIdentity>,
+    pub status: MetadataImportStatus,
+    pub changes: Vec<MetadataChange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Changes to an existing metadata field definition. Unity does not allow changing the field type.
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+pub struct MetadataDefinitionUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "acceptedValues")]
+    pub accepted_values: Option<Vec<String>>,
+}
+
+impl MetadataDefinitionUpdate {
+    /// Returns true if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.display_name.is_none() && self.accepted_values.is_none()
+    }
+}
+
+/// Declarative description of the metadata fields expected in the organization.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct MetadataSchema {
+    pub fields: Vec<MetadataFieldSchema>,
+}
+
+/// A single field in the metadata schema.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct MetadataFieldSchema {
+    pub name: String,
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+    #[serde(rename = "type")]
+    pub field_type: MetadataFieldType,
+    #[serde(rename = "acceptedValues")]
+    pub accepted_values: Option<Vec<String>>,
+}
+
+impl MetadataFieldSchema {
+    /// Returns the definition described by this schema entry. The display name defaults to the field name.
+    pub fn definition(&self) -> MetadataDefinition {
+        MetadataDefinition::new(
+            self.name.to_owned(),
+            self.display_name
+                .to_owned()
+                .unwrap_or_else(|| self.name.to_owned()),
+            self.field_type,
+            self.accepted_values.to_owned(),
+        )
+    }
+
+    /// Compares the schema entry with the existing definition in the organization.
+    ///
+    /// Parameters:
+    ///
+    /// * existing - the existing definition or None if the field does not exist
+    pub fn drift(&self, existing: Option<&MetadataDefinition>) -> MetadataSchemaDrift {
+        let expected = self.definition();
+        let existing = match existing {
+            Some(existing) => existing,
+            None => {
+                return MetadataSchemaDrift {
+                    name: self.name.to_owned(),
+                    action: MetadataSchemaAction::Create,
+                    differences: vec![],
+                    changes: None,
+                }
+            }
+        };
+
+        let mut differences = vec![];
+        let mut changes = MetadataDefinitionUpdate::default();
+
+        if existing.field_type() != expected.field_type() {
+            differences.push(format!(
+                "type: {} -> {}",
+                existing.field_type(),
+                expected.field_type()
+            ));
+        }
+        if existing.display_name != expected.display_name {
+            differences.push(format!(
+                "displayName: {} -> {}",
+                existing.display_name, expected.display_name
+            ));
+            changes.display_name = Some(expected.display_name.to_owned());
+        }
+        let existing_values = existing.accepted_values.to_owned().unwrap_or_default();
+        let expected_values = expected.accepted_values.to_owned().unwrap_or_default();
+        if existing_values != expected_values {
+            differences.push(format!(
+                "acceptedValues: [{}] -> [{}]",
+                existing_values.join(", "),
+                expected_values.join(", ")
+            ));
+            changes.accepted_values = Some(expected_values);
+        }
+
+        let action = if existing.field_type() != expected.field_type() {
+            MetadataSchemaAction::Conflict
+        } else if changes.is_empty() {
+            MetadataSchemaAction::Unchanged
+        } else {
+            MetadataSchemaAction::Update
+        };
+
+        MetadataSchemaDrift {
+            name: self.name.to_owned(),
+            action,
+            differences,
+            changes: if action == MetadataSchemaAction::Update {
+                Some(changes)
+            } else {
+                None
+            },
+        }
+    }
+}
+
+/// What needs to happen to a field definition to match the schema.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MetadataSchemaAction {
+    /// The field does not exist and will be created.
+    Create,
+    /// The display name or the accepted values differ and will be updated.
+    Update,
+    /// The field type differs. Unity does not allow changing it, so it has to be fixed manually.
+    Conflict,
+    /// The field matches the schema.
+    Unchanged,
+    /// The field exists in the organization, but not in the schema. It is left as is.
+    Unmanaged,
+}
+
+/// Difference between the schema and the organization for a single field.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct MetadataSchemaDrift {
+    pub name: String,
+    pub action: MetadataSchemaAction,
+    pub differences: Vec<String>,
+    #[serde(skip)]
+    pub changes: Option<MetadataDefinitionUpdate>,
+}
+
+/// Attributes read from a sidecar metadata file stored next to a data file.
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+pub struct SidecarMetadata {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub metadata: HashMap<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn definition(
+        field_type: MetadataFieldType,
+        accepted_values: Option<Vec<&str>>,
+    ) -> MetadataDefinition {
+        MetadataDefinition::new(
+            "Field".to_string(),
+            "Field".to_string(),
+            field_type,
+            accepted_values.map(|values| values.into_iter().map(String::from).collect()),
+        )
+    }
+
+    #[test]
+    fn coerce_number_parses_strings() {
+        let number = definition(MetadataFieldType::Number, None);
+        assert_eq!(number.coerce(&json!("42")).unwrap(), json!(42));
+        assert_eq!(number.coerce(&json!(" 1.5 ")).unwrap(), json!(1.5));
+        assert_eq!(number.coerce(&json!(7)).unwrap(), json!(7));
+        assert!(number.coerce(&json!("seven")).is_err());
+        assert!(number.coerce(&json!(true)).is_err());
+    }
+
+    #[test]
+    fn coerce_text_converts_scalars() {
+        let text = definition(MetadataFieldType::Text, None);
+        assert_eq!(text.coerce(&json!("steel")).unwrap(), json!("steel"));
+        assert_eq!(text.coerce(&json!(12)).unwrap(), json!("12"));
+        assert_eq!(text.coerce(&json!(false)).unwrap(), json!("false"));
+        assert!(text.coerce(&json!(["a"])).is_err());
+    }
+
+    #[test]
+    fn coerce_boolean_accepts_common_spellings() {
+        let boolean = definition(MetadataFieldType::Boolean, None);
+        assert_eq!(boolean.coerce(&json!("Yes")).unwrap(), json!(true));
+        assert_eq!(boolean.coerce(&json!("0")).unwrap(), json!(false));
+        assert_eq!(boolean.coerce(&json!(true)).unwrap(), json!(true));
+        assert!(boolean.coerce(&json!("maybe")).is_err());
+    }
+
+    #[test]
+    fn coerce_timestamp_accepts_dates() {
+        let timestamp = definition(MetadataFieldType::Timestamp, None);
+        assert_eq!(
+            timestamp.coerce(&json!("2024-03-01")).unwrap(),
+            json!("2024-03-01T00:00:00+00:00")
+        );
+        assert_eq!(
+            timestamp.coerce(&json!("2024-03-01T12:30:00Z")).unwrap(),
+            json!("2024-03-01T12:30:00+00:00")
+        );
+        assert!(timestamp.coerce(&json!("yesterday")).is_err());
+    }
+
+    #[test]
+    fn coerce_url_requires_a_valid_url() {
+        let url = definition(MetadataFieldType::Url, None);
+        assert_eq!(
+            url.coerce(&json!(" https://example.com/a ")).unwrap(),
+            json!("https://example.com/a")
+        );
+        assert!(url.coerce(&json!("not a url")).is_err());
+    }
+
+    #[test]
+    fn coerce_selection_checks_accepted_values() {
+        let single = definition(MetadataFieldType::SingleSelect, Some(vec!["red", "blue"]));
+        assert_eq!(single.coerce(&json!("red")).unwrap(), json!("red"));
+        assert!(single.coerce(&json!("green")).is_err());
+
+        let multi = definition(MetadataFieldType::MultiSelect, Some(vec!["red", "blue"]));
+        assert_eq!(
+            multi.coerce(&json!(["red", "blue"])).unwrap(),
+            json!(["red", "blue"])
+        );
+        assert_eq!(
+            multi.coerce(&json!("red, blue")).unwrap(),
+            json!(["red", "blue"])
+        );
+        assert_eq!(multi.coerce(&json!("[\"blue\"]")).unwrap(), json!(["blue"]));
+        assert!(multi.coerce(&json!(["red", "green"])).is_err());
+        assert!(multi.coerce(&json!([1])).is_err());
+    }
+
+    #[test]
+    fn path_to_same_status_is_empty() {
+        assert_eq!(
+            AssetStatus::Draft.path_to(&AssetStatus::Draft),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn path_to_direct_transition() {
+        assert_eq!(
+            AssetStatus::Approved.path_to(&AssetStatus::Published),
+            Some(vec![AssetStatus::Published])
+        );
+    }
+
+    #[test]
+    fn path_to_runs_through_review() {
+        assert_eq!(
+            AssetStatus::Draft.path_to(&AssetStatus::Published),
+            Some(vec![
+                AssetStatus::InReview,
+                AssetStatus::Approved,
+                AssetStatus::Published
+            ])
+        );
+        assert_eq!(
+            AssetStatus::InReview.path_to(&AssetStatus::Draft),
+            Some(vec![AssetStatus::Rejected, AssetStatus::Draft])
+        );
+    }
+
+    #[test]
+    fn path_to_after_rejection() {
+        assert_eq!(
+            AssetStatus::Rejected.path_to(&AssetStatus::Approved),
+            Some(vec![
+                AssetStatus::Draft,
+                AssetStatus::InReview,
+                AssetStatus::Approved
+            ])
+        );
+    }
+
+    #[test]
+    fn path_to_unreachable_status() {
+        assert_eq!(AssetStatus::Published.path_to(&AssetStatus::Draft), None);
+        assert_eq!(
+            AssetStatus::Withdrawn.path_to(&AssetStatus::Published),
+            None
+        );
+    }
+
+    #[test]
+    fn path_to_unknown_status() {
+        let unknown = AssetStatus::Unknown("Archived".to_string());
+        assert_eq!(unknown.path_to(&AssetStatus::Draft), None);
+        assert_eq!(AssetStatus::Draft.path_to(&unknown), None);
+        assert_eq!(unknown.path_to(&unknown), Some(vec![]));
+    }
+
+    #[test]
+    fn workflow_lists_all_transitions() {
+        assert_eq!(
+            AssetStatus::workflow(),
+            "Draft -> InReview, InReview -> Approved|Rejected, Approved -> Published, Published -> Withdrawn, Rejected -> Draft"
+        );
+    }
+
+    fn version(version: &str, number: Option<u64>, frozen: bool, created: &str) -> AssetVersion {
+        serde_json::from_value(serde_json::json!({
+            "assetId": "a",
+            "assetVersion": version,
+            "versionNumber": number,
+            "isFrozen": frozen,
+            "created": created,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn sort_latest_first_matches_latest() {
+        let mut versions = vec![
+            version("v1", Some(1), true, "2024-01-01T00:00:00Z"),
+            version("draft-old", None, false, "2024-02-01T00:00:00Z"),
+            version("v2", Some(2), true, "2024-01-15T00:00:00Z"),
+            version("draft-new", None, false, "2024-03-01T00:00:00Z"),
+        ];
+        let latest = AssetVersion::latest(&versions).unwrap().version();
+
+        AssetVersion::sort_latest_first(&mut versions);
+        let sorted: Vec<String> = versions.iter().map(|v| v.version()).collect();
+        assert_eq!(sorted, vec!["draft-new", "draft-old", "v2", "v1"]);
+        assert_eq!(sorted[0], latest);
+    }
 }
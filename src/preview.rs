@@ -0,0 +1,713 @@
+//! Renders preview images of 3D geometry without a GPU.
+//!
+//! Meshes are loaded from STL, OBJ, PLY and glTF files and drawn by a small software rasterizer
+//! (isometric orthographic view, flat shading, depth buffer) into an RGBA PNG image with a
+//! transparent background. It runs on machines without a display or graphics driver.
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Read},
+    path::Path,
+};
+use thiserror::Error;
+
+/// File extensions of the supported geometry formats.
+pub const SUPPORTED_EXTENSIONS: [&str; 5] = ["stl", "obj", "ply", "gltf", "glb"];
+
+/// Default width and height of the preview image in pixels.
+pub const DEFAULT_PREVIEW_SIZE: u32 = 512;
+
+/// Number of samples per pixel in each direction used for anti-aliasing.
+const SUPERSAMPLING: u32 = 2;
+/// Fraction of the image covered by the bounding sphere of the model.
+const FILL_FACTOR: f32 = 0.9;
+/// Base color of the model surface (RGB, 0-1).
+const SURFACE_COLOR: [f32; 3] = [0.62, 0.68, 0.76];
+/// Light intensity received by surfaces facing away from the light.
+const AMBIENT_LIGHT: f32 = 0.25;
+
+/// A wrapper for all errors generated when rendering a preview.
+#[derive(Debug, Error)]
+pub enum PreviewError {
+    #[error("unsupported geometry format {0}")]
+    UnsupportedFormat(String),
+    #[error("input/output error")]
+    InputOutput(#[from] std::io::Error),
+    #[error("OBJ parsing error")]
+    ObjParse(#[from] tobj::LoadError),
+    #[error("glTF parsing error")]
+    GltfParse(#[from] gltf::Error),
+    #[error("PNG encoding error")]
+    PngEncoding(#[from] png::EncodingError),
+    #[error("the file does not contain any triangles")]
+    EmptyMesh,
+    #[error("invalid PLY file: {0}")]
+    InvalidPly(String),
+}
+
+/// A triangle given by the coordinates of its three vertices.
+pub type Triangle = [[f32; 3]; 3];
+
+/// Triangle soup with a Y-up coordinate system.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Mesh {
+    triangles: Vec<Triangle>,
+}
+
+impl Mesh {
+    pub fn new(triangles: Vec<Triangle>) -> Self {
+        Self { triangles }
+    }
+
+    pub fn triangles(&self) -> &[Triangle] {
+        &self.triangles
+    }
+
+    /// Returns true if the file extension is one of the supported geometry formats.
+    pub fn is_supported(path: &Path) -> bool {
+        Self::extension(path).is_some_and(|e| SUPPORTED_EXTENSIONS.contains(&e.as_str()))
+    }
+
+    /// Loads the geometry of a STL, OBJ, PLY or glTF (.gltf, .glb) file.
+    ///
+    /// STL files are assumed to be Z-up, as usual for CAD exports, and are rotated to Y-up.
+    ///
+    /// Parameters:
+    /// * path: the geometry file
+    pub fn load(path: &Path) -> Result<Mesh, PreviewError> {
+        let triangles = match Self::extension(path).as_deref() {
+            Some("stl") => Self::load_stl(path)?,
+            Some("obj") => Self::load_obj(path)?,
+            Some("ply") => Self::load_ply(path)?,
+            Some("gltf") | Some("glb") => Self::load_gltf(path)?,
+            _ => {
+                return Err(PreviewError::UnsupportedFormat(
+                    path.to_string_lossy().to_string(),
+                ))
+            }
+        };
+
+        match triangles.is_empty() {
+            true => Err(PreviewError::EmptyMesh),
+            false => Ok(Mesh::new(triangles)),
+        }
+    }
+
+    /// Returns the lowercase file extension.
+    pub(crate) fn extension(path: &Path) -> Option<String> {
+        path.extension().map(|e| e.to_string_lossy().to_lowercase())
+    }
+
+    fn load_stl(path: &Path) -> Result<Vec<Triangle>, PreviewError> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mesh = stl_io::read_stl(&mut reader)?;
+
+        Ok(mesh
+            .faces
+            .iter()
+            .map(|face| {
+                face.vertices.map(|i| {
+                    let [x, y, z] = mesh.vertices[i].0;
+                    [x, z, -y]
+                })
+            })
+            .collect())
+    }
+
+    fn load_obj(path: &Path) -> Result<Vec<Triangle>, PreviewError> {
+        let options = tobj::LoadOptions {
+            triangulate: true,
+            ignore_points: true,
+            ignore_lines: true,
+            ..Default::default()
+        };
+        // the materials are not needed, so a missing MTL file is not an error
+        let (models, _materials) = tobj::load_obj(path, &options)?;
+
+        let mut triangles = vec![];
+        for model in models {
+            let positions = &model.mesh.positions;
+            let vertex = |i: u32| {
+                let i = i as usize * 3;
+                [positions[i], positions[i + 1], positions[i + 2]]
+            };
+            for face in model.mesh.indices.chunks_exact(3) {
+                triangles.push([vertex(face[0]), vertex(face[1]), vertex(face[2])]);
+            }
+        }
+
+        Ok(triangles)
+    }
+
+    fn load_ply(path: &Path) -> Result<Vec<Triangle>, PreviewError> {
+        let ply = read_ply(path)?;
+
+        let mut triangles = vec![];
+        for face in ply.faces {
+            // polygons are split into a fan of triangles
+            for i in 1..face.len().saturating_sub(1) {
+                let vertex = |i: usize| ply.vertices.get(face[i]).copied();
+                match (vertex(0), vertex(i), vertex(i + 1)) {
+                    (Some(a), Some(b), Some(c)) => triangles.push([a, b, c]),
+                    _ => {
+                        return Err(PreviewError::InvalidPly(
+                            "vertex index out of range".to_string(),
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(triangles)
+    }
+
+    fn load_gltf(path: &Path) -> Result<Vec<Triangle>, PreviewError> {
+        let (document, buffers, _images) = gltf::import(path)?;
+        let mut triangles = vec![];
+
+        let scene = document
+            .default_scene()
+            .or_else(|| document.scenes().next());
+        match scene {
+            Some(scene) => {
+                for node in scene.nodes() {
+                    Self::load_gltf_node(&node, &IDENTITY, &buffers, &mut triangles);
+                }
+            }
+            None => {
+                for mesh in document.meshes() {
+                    Self::load_gltf_mesh(&mesh, &IDENTITY, &buffers, &mut triangles);
+                }
+            }
+        }
+
+        Ok(triangles)
+    }
+
+    fn load_gltf_node(
+        node: &gltf::Node,
+        parent: &Matrix,
+        buffers: &[gltf::buffer::Data],
+        triangles: &mut Vec<Triangle>,
+    ) {
+        let transform = multiply(parent, &node.transform().matrix());
+        if let Some(mesh) = node.mesh() {
+            Self::load_gltf_mesh(&mesh, &transform, buffers, triangles);
+        }
+        for child in node.children() {
+            Self::load_gltf_node(&child, &transform, buffers, triangles);
+        }
+    }
+
+    fn load_gltf_mesh(
+        mesh: &gltf::Mesh,
+        transform: &Matrix,
+        buffers: &[gltf::buffer::Data],
+        triangles: &mut Vec<Triangle>,
+    ) {
+        for primitive in mesh.primitives() {
+            if primitive.mode() != gltf::mesh::Mode::Triangles {
+                continue;
+            }
+
+            let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+            let positions: Vec<[f32; 3]> = match reader.read_positions() {
+                Some(positions) => positions.map(|p| transform_point(transform, p)).collect(),
+                None => continue,
+            };
+            let indices: Vec<u32> = match reader.read_indices() {
+                Some(indices) => indices.into_u32().collect(),
+                None => (0..positions.len() as u32).collect(),
+            };
+
+            for face in indices.chunks_exact(3) {
+                triangles.push([
+                    positions[face[0] as usize],
+                    positions[face[1] as usize],
+                    positions[face[2] as usize],
+                ]);
+            }
+        }
+    }
+}
+
+/// Vertices, faces and comments of a PLY file.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PlyData {
+    pub vertices: Vec<[f32; 3]>,
+    /// Vertex indices of each polygon.
+    pub faces: Vec<Vec<usize>>,
+    pub comments: Vec<String>,
+}
+
+/// Property of a PLY element: either a scalar type or a list with the count and item types.
+enum PlyProperty {
+    Scalar(String, String),
+    List(String, String, String),
+}
+
+/// Data section of a PLY file.
+enum PlyInput<R: Read> {
+    Ascii(std::vec::IntoIter<String>),
+    Binary { reader: R, big_endian: bool },
+}
+
+impl<R: Read> PlyInput<R> {
+    /// Reads the next value of a PLY scalar type.
+    fn next(&mut self, value_type: &str) -> Result<f64, PreviewError> {
+        match self {
+            PlyInput::Ascii(tokens) => {
+                tokens
+                    .next()
+                    .and_then(|t| t.parse::<f64>().ok())
+                    .ok_or(PreviewError::InvalidPly(
+                        "unexpected end of data".to_string(),
+                    ))
+            }
+            PlyInput::Binary { reader, big_endian } => {
+                let size = match value_type {
+                    "char" | "int8" | "uchar" | "uint8" => 1,
+                    "short" | "int16" | "ushort" | "uint16" => 2,
+                    "int" | "int32" | "uint" | "uint32" | "float" | "float32" => 4,
+                    "double" | "float64" => 8,
+                    _ => {
+                        return Err(PreviewError::InvalidPly(format!(
+                            "unknown type {}",
+                            value_type
+                        )))
+                    }
+                };
+                let mut bytes = [0u8; 8];
+                let bytes = &mut bytes[..size];
+                reader.read_exact(bytes)?;
+                if *big_endian {
+                    bytes.reverse();
+                }
+                let value = match value_type {
+                    "char" | "int8" => bytes[0] as i8 as f64,
+                    "uchar" | "uint8" => bytes[0] as f64,
+                    "short" | "int16" => i16::from_le_bytes([bytes[0], bytes[1]]) as f64,
+                    "ushort" | "uint16" => u16::from_le_bytes([bytes[0], bytes[1]]) as f64,
+                    "int" | "int32" => i32::from_le_bytes(bytes.try_into().unwrap()) as f64,
+                    "uint" | "uint32" => u32::from_le_bytes(bytes.try_into().unwrap()) as f64,
+                    "float" | "float32" => f32::from_le_bytes(bytes.try_into().unwrap()) as f64,
+                    _ => f64::from_le_bytes(bytes.try_into().unwrap()),
+                };
+                Ok(value)
+            }
+        }
+    }
+}
+
+/// Reads the vertex positions and faces of an ASCII or binary PLY file.
+///
+/// Parameters:
+/// * path: the PLY file
+pub fn read_ply(path: &Path) -> Result<PlyData, PreviewError> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut ply = PlyData::default();
+    let mut format = String::new();
+    let mut elements: Vec<(String, usize, Vec<PlyProperty>)> = vec![];
+
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    if line.trim() != "ply" {
+        return Err(PreviewError::InvalidPly("missing ply header".to_string()));
+    }
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(PreviewError::InvalidPly("missing end_header".to_string()));
+        }
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            ["end_header"] => break,
+            ["format", value, ..] => format = value.to_string(),
+            ["comment", ..] | ["obj_info", ..] => {
+                ply.comments.push(words[1..].join(" "));
+            }
+            ["element", name, count] => {
+                let count = count
+                    .parse()
+                    .map_err(|_| PreviewError::InvalidPly(line.trim().to_owned()))?;
+                elements.push((name.to_string(), count, vec![]));
+            }
+            ["property", "list", count_type, item_type, name] => match elements.last_mut() {
+                Some(element) => element.2.push(PlyProperty::List(
+                    name.to_string(),
+                    count_type.to_string(),
+                    item_type.to_string(),
+                )),
+                None => return Err(PreviewError::InvalidPly(line.trim().to_owned())),
+            },
+            ["property", value_type, name] => match elements.last_mut() {
+                Some(element) => element.2.push(PlyProperty::Scalar(
+                    name.to_string(),
+                    value_type.to_string(),
+                )),
+                None => return Err(PreviewError::InvalidPly(line.trim().to_owned())),
+            },
+            _ => (),
+        }
+    }
+
+    let mut input = match format.as_str() {
+        "ascii" => {
+            let mut data = String::new();
+            reader.read_to_string(&mut data)?;
+            let tokens: Vec<String> = data.split_whitespace().map(|t| t.to_owned()).collect();
+            PlyInput::Ascii(tokens.into_iter())
+        }
+        "binary_little_endian" => PlyInput::Binary {
+            reader,
+            big_endian: false,
+        },
+        "binary_big_endian" => PlyInput::Binary {
+            reader,
+            big_endian: true,
+        },
+        _ => {
+            return Err(PreviewError::InvalidPly(format!(
+                "unknown format {}",
+                format
+            )))
+        }
+    };
+
+    for (element, count, properties) in &elements {
+        for _ in 0..*count {
+            let mut position = [0.0f32; 3];
+            for property in properties {
+                match property {
+                    PlyProperty::Scalar(name, value_type) => {
+                        let value = input.next(value_type)?;
+                        match name.as_str() {
+                            "x" => position[0] = value as f32,
+                            "y" => position[1] = value as f32,
+                            "z" => position[2] = value as f32,
+                            _ => (),
+                        }
+                    }
+                    PlyProperty::List(name, count_type, item_type) => {
+                        let length = input.next(count_type)? as usize;
+                        // the length comes from the file, so it is not used to pre-allocate
+                        let mut items = vec![];
+                        for _ in 0..length {
+                            items.push(input.next(item_type)? as usize);
+                        }
+                        if element == "face" && name.starts_with("vertex_ind") {
+                            ply.faces.push(items);
+                        }
+                    }
+                }
+            }
+            if element == "vertex" {
+                ply.vertices.push(position);
+            }
+        }
+    }
+
+    Ok(ply)
+}
+
+/// Column-major 4x4 transformation matrix as used by glTF.
+pub(crate) type Matrix = [[f32; 4]; 4];
+
+pub(crate) const IDENTITY: Matrix = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [0.0, 0.0, 0.0, 1.0],
+];
+
+pub(crate) fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut result = [[0.0; 4]; 4];
+    for (column, result_column) in result.iter_mut().enumerate() {
+        for (row, value) in result_column.iter_mut().enumerate() {
+            *value = (0..4).map(|k| a[k][row] * b[column][k]).sum();
+        }
+    }
+    result
+}
+
+pub(crate) fn transform_point(m: &Matrix, p: [f32; 3]) -> [f32; 3] {
+    let mut result = [0.0; 3];
+    for (row, value) in result.iter_mut().enumerate() {
+        *value = m[0][row] * p[0] + m[1][row] * p[1] + m[2][row] * p[2] + m[3][row];
+    }
+    result
+}
+
+fn subtract(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn normalize(a: [f32; 3]) -> [f32; 3] {
+    let length = dot(a, a).sqrt();
+    match length > 0.0 {
+        true => [a[0] / length, a[1] / length, a[2] / length],
+        false => a,
+    }
+}
+
+/// Renders the mesh from an isometric viewpoint and returns the RGBA pixels of a square image,
+/// row by row from the top.
+///
+/// Parameters:
+/// * mesh: the geometry to render
+/// * size: the width and height of the image in pixels
+pub fn render(mesh: &Mesh, size: u32) -> Vec<u8> {
+    let width = (size * SUPERSAMPLING) as usize;
+    let mut color = vec![[0.0f32; 4]; width * width];
+    let mut depth = vec![f32::NEG_INFINITY; width * width];
+
+    // view transformation: center the model, turn it by 45 degrees around the vertical axis
+    // and tilt it towards the viewer by the isometric angle
+    let vertices = mesh.triangles().iter().flatten();
+    let (min, max) = vertices
+        .clone()
+        .fold(([f32::MAX; 3], [f32::MIN; 3]), |(min, max), v| {
+            (
+                [min[0].min(v[0]), min[1].min(v[1]), min[2].min(v[2])],
+                [max[0].max(v[0]), max[1].max(v[1]), max[2].max(v[2])],
+            )
+        });
+    let center = [
+        (min[0] + max[0]) / 2.0,
+        (min[1] + max[1]) / 2.0,
+        (min[2] + max[2]) / 2.0,
+    ];
+    let radius = vertices
+        .map(|v| {
+            let d = subtract(*v, center);
+            dot(d, d)
+        })
+        .fold(0.0f32, f32::max)
+        .sqrt();
+    let scale = match radius > 0.0 {
+        true => width as f32 * FILL_FACTOR / (2.0 * radius),
+        false => 1.0,
+    };
+    let (yaw_sin, yaw_cos) = std::f32::consts::FRAC_PI_4.sin_cos();
+    let (pitch_sin, pitch_cos) = (1.0f32 / 2.0f32.sqrt()).atan().sin_cos();
+    let view = |v: [f32; 3]| {
+        let [x, y, z] = subtract(v, center);
+        let (x, z) = (x * yaw_cos + z * yaw_sin, -x * yaw_sin + z * yaw_cos);
+        let (y, z) = (y * pitch_cos - z * pitch_sin, y * pitch_sin + z * pitch_cos);
+        [x, y, z]
+    };
+    let light = normalize([-0.4, 0.7, 0.6]);
+    let half = width as f32 / 2.0;
+
+    for triangle in mesh.triangles() {
+        let triangle = triangle.map(view);
+
+        // flat shading, lit from both sides since the winding order is not reliable
+        let normal = normalize(cross(
+            subtract(triangle[1], triangle[0]),
+            subtract(triangle[2], triangle[0]),
+        ));
+        let intensity = AMBIENT_LIGHT + (1.0 - AMBIENT_LIGHT) * dot(normal, light).abs();
+        let shade = [
+            SURFACE_COLOR[0] * intensity,
+            SURFACE_COLOR[1] * intensity,
+            SURFACE_COLOR[2] * intensity,
+            1.0,
+        ];
+
+        let screen = triangle.map(|[x, y, z]| [half + x * scale, half - y * scale, z]);
+        let [a, b, c] = screen;
+        let area = (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+        if area.abs() < f32::EPSILON {
+            continue;
+        }
+
+        let x_min = a[0].min(b[0]).min(c[0]).floor().max(0.0) as usize;
+        let x_max = (a[0].max(b[0]).max(c[0]).ceil() as usize).min(width);
+        let y_min = a[1].min(b[1]).min(c[1]).floor().max(0.0) as usize;
+        let y_max = (a[1].max(b[1]).max(c[1]).ceil() as usize).min(width);
+
+        for y in y_min..y_max {
+            for x in x_min..x_max {
+                let (px, py) = (x as f32 + 0.5, y as f32 + 0.5);
+                let w0 = ((c[0] - b[0]) * (py - b[1]) - (c[1] - b[1]) * (px - b[0])) / area;
+                let w1 = ((a[0] - c[0]) * (py - c[1]) - (a[1] - c[1]) * (px - c[0])) / area;
+                let w2 = 1.0 - w0 - w1;
+                if w0 < 0.0 || w1 < 0.0 || w2 < 0.0 {
+                    continue;
+                }
+
+                let z = w0 * a[2] + w1 * b[2] + w2 * c[2];
+                let i = y * width + x;
+                if z > depth[i] {
+                    depth[i] = z;
+                    color[i] = shade;
+                }
+            }
+        }
+    }
+
+    // average the samples of each pixel
+    let size = size as usize;
+    let samples = SUPERSAMPLING as usize;
+    let mut pixels = Vec::with_capacity(size * size * 4);
+    for y in 0..size {
+        for x in 0..size {
+            let mut sum = [0.0f32; 4];
+            for sy in 0..samples {
+                for sx in 0..samples {
+                    let sample = color[(y * samples + sy) * width + x * samples + sx];
+                    for channel in 0..3 {
+                        sum[channel] += sample[channel] * sample[3];
+                    }
+                    sum[3] += sample[3];
+                }
+            }
+            let alpha = sum[3] / (samples * samples) as f32;
+            for channel in sum.iter().take(3) {
+                let value = match sum[3] > 0.0 {
+                    true => channel / sum[3],
+                    false => 0.0,
+                };
+                pixels.push((value * 255.0).round() as u8);
+            }
+            pixels.push((alpha * 255.0).round() as u8);
+        }
+    }
+
+    pixels
+}
+
+/// Renders the mesh and writes the image to a PNG file.
+///
+/// Parameters:
+/// * mesh: the geometry to render
+/// * size: the width and height of the image in pixels
+/// * output: the path of the PNG file
+pub fn render_png(mesh: &Mesh, size: u32, output: &Path) -> Result<(), PreviewError> {
+    let pixels = render(mesh, size);
+
+    let writer = BufWriter::new(File::create(output)?);
+    let mut encoder = png::Encoder::new(writer, size, size);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&pixels)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn ply_file(content: &[u8]) -> tempfile::NamedTempFile {
+        let mut file = tempfile::Builder::new().suffix(".ply").tempfile().unwrap();
+        file.write_all(content).unwrap();
+        file
+    }
+
+    #[test]
+    fn read_ascii_ply() {
+        let file = ply_file(
+            b"ply
+format ascii 1.0
+comment TextureFile wood.png
+element vertex 4
+property float x
+property float y
+property float z
+element face 1
+property list uchar int vertex_indices
+end_header
+0 0 0
+1 0 0
+1 1 0
+0 1 0
+4 0 1 2 3
+",
+        );
+
+        let ply = read_ply(file.path()).unwrap();
+        assert_eq!(
+            ply.vertices,
+            vec![
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 1.0, 0.0],
+                [0.0, 1.0, 0.0]
+            ]
+        );
+        assert_eq!(ply.faces, vec![vec![0, 1, 2, 3]]);
+        assert_eq!(ply.comments, vec!["TextureFile wood.png"]);
+    }
+
+    #[test]
+    fn read_binary_ply() {
+        let mut content = b"ply
+format binary_little_endian 1.0
+element vertex 3
+property float x
+property float y
+property float z
+element face 1
+property list uchar uint vertex_indices
+end_header
+"
+        .to_vec();
+        for value in [0.0f32, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 0.0] {
+            content.extend(value.to_le_bytes());
+        }
+        content.push(3);
+        for index in [0u32, 1, 2] {
+            content.extend(index.to_le_bytes());
+        }
+        let file = ply_file(&content);
+
+        let ply = read_ply(file.path()).unwrap();
+        assert_eq!(
+            ply.vertices,
+            vec![[0.0, 0.0, 0.0], [2.0, 0.0, 0.0], [0.0, 3.0, 0.0]]
+        );
+        assert_eq!(ply.faces, vec![vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn read_ply_rejects_missing_header() {
+        let file = ply_file(b"solid cube\n");
+        assert!(matches!(
+            read_ply(file.path()),
+            Err(PreviewError::InvalidPly(_))
+        ));
+    }
+
+    #[test]
+    fn read_ply_with_oversized_list_count() {
+        // the count is not used to pre-allocate, so the missing data is reported as an error
+        let file = ply_file(
+            b"ply
+format ascii 1.0
+element face 1
+property list uint int vertex_indices
+end_header
+4294967295 0 1 2
+",
+        );
+        assert!(matches!(
+            read_ply(file.path()),
+            Err(PreviewError::InvalidPly(_))
+        ));
+    }
+}
@@ -0,0 +1,328 @@
+//! Extracts statistics from 3D model files.
+//!
+//! The statistics (triangle and vertex counts, bounding box, units, materials and texture references)
+//! are computed locally and can be stored as asset metadata.
+use crate::{
+    model::MetadataFieldType,
+    preview::{self, Matrix, Mesh, PreviewError, IDENTITY},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+
+/// Metadata field names of the statistics.
+pub const FIELD_TRIANGLE_COUNT: &str = "TriangleCount";
+pub const FIELD_VERTEX_COUNT: &str = "VertexCount";
+pub const FIELD_DIMENSION_X: &str = "DimensionX";
+pub const FIELD_DIMENSION_Y: &str = "DimensionY";
+pub const FIELD_DIMENSION_Z: &str = "DimensionZ";
+pub const FIELD_UNITS: &str = "Units";
+pub const FIELD_MATERIAL_COUNT: &str = "MaterialCount";
+pub const FIELD_TEXTURES: &str = "TextureReferences";
+
+/// Statistics of one or more 3D model files.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelStats {
+    /// The analyzed files.
+    pub files: Vec<String>,
+    pub triangles: usize,
+    pub vertices: usize,
+    /// Minimum corner of the axis-aligned bounding box in file coordinates.
+    pub bounding_box_min: [f32; 3],
+    /// Maximum corner of the axis-aligned bounding box in file coordinates.
+    pub bounding_box_max: [f32; 3],
+    /// Length unit, if the format defines one (glTF uses meters).
+    pub units: Option<String>,
+    pub materials: usize,
+    /// External texture files referenced by the model.
+    pub textures: Vec<String>,
+}
+
+impl ModelStats {
+    /// Returns true if the statistics can be extracted from the file.
+    pub fn is_supported(path: &Path) -> bool {
+        Mesh::is_supported(path)
+    }
+
+    /// Computes the statistics of a STL, OBJ, PLY or glTF (.gltf, .glb) file.
+    ///
+    /// Parameters:
+    /// * path: the model file
+    pub fn from_file(path: &Path) -> Result<ModelStats, PreviewError> {
+        let mut stats = match Mesh::extension(path).as_deref() {
+            Some("stl") => Self::from_stl(path)?,
+            Some("obj") => Self::from_obj(path)?,
+            Some("ply") => Self::from_ply(path)?,
+            Some("gltf") | Some("glb") => Self::from_gltf(path)?,
+            _ => {
+                return Err(PreviewError::UnsupportedFormat(
+                    path.to_string_lossy().to_string(),
+                ))
+            }
+        };
+        stats.files = vec![path.to_string_lossy().to_string()];
+
+        Ok(stats)
+    }
+
+    /// Computes the combined statistics of several files. Counts are added, the bounding boxes
+    /// are joined and the units are kept only if all files agree.
+    ///
+    /// Parameters:
+    /// * stats: the statistics of the individual files
+    pub fn combine(stats: &[ModelStats]) -> ModelStats {
+        let mut combined = match stats.first() {
+            Some(first) => first.clone(),
+            None => return ModelStats::default(),
+        };
+
+        for other in &stats[1..] {
+            combined.files.extend(other.files.iter().cloned());
+            combined.triangles += other.triangles;
+            combined.vertices += other.vertices;
+            for axis in 0..3 {
+                combined.bounding_box_min[axis] =
+                    combined.bounding_box_min[axis].min(other.bounding_box_min[axis]);
+                combined.bounding_box_max[axis] =
+                    combined.bounding_box_max[axis].max(other.bounding_box_max[axis]);
+            }
+            if combined.units != other.units {
+                combined.units = None;
+            }
+            combined.materials += other.materials;
+            for texture in &other.textures {
+                if !combined.textures.contains(texture) {
+                    combined.textures.push(texture.to_owned());
+                }
+            }
+        }
+
+        combined
+    }
+
+    /// Returns the size of the bounding box along each axis.
+    pub fn dimensions(&self) -> [f32; 3] {
+        [
+            self.bounding_box_max[0] - self.bounding_box_min[0],
+            self.bounding_box_max[1] - self.bounding_box_min[1],
+            self.bounding_box_max[2] - self.bounding_box_min[2],
+        ]
+    }
+
+    /// Returns the statistics as metadata field values.
+    pub fn metadata(&self) -> Vec<(String, Value)> {
+        let [x, y, z] = self.dimensions();
+        let mut values = vec![
+            (FIELD_TRIANGLE_COUNT, Value::from(self.triangles)),
+            (FIELD_VERTEX_COUNT, Value::from(self.vertices)),
+            (FIELD_DIMENSION_X, Self::dimension_value(x)),
+            (FIELD_DIMENSION_Y, Self::dimension_value(y)),
+            (FIELD_DIMENSION_Z, Self::dimension_value(z)),
+            (FIELD_MATERIAL_COUNT, Value::from(self.materials)),
+        ];
+        if let Some(units) = &self.units {
+            values.push((FIELD_UNITS, Value::String(units.to_owned())));
+        }
+        if !self.textures.is_empty() {
+            values.push((FIELD_TEXTURES, Value::String(self.textures.join(", "))));
+        }
+
+        values
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect()
+    }
+
+    /// Returns the metadata field type of a statistics field, or None for other fields.
+    pub fn field_type(name: &str) -> Option<MetadataFieldType> {
+        match name {
+            FIELD_TRIANGLE_COUNT | FIELD_VERTEX_COUNT | FIELD_DIMENSION_X | FIELD_DIMENSION_Y
+            | FIELD_DIMENSION_Z | FIELD_MATERIAL_COUNT => Some(MetadataFieldType::Number),
+            FIELD_UNITS | FIELD_TEXTURES => Some(MetadataFieldType::Text),
+            _ => None,
+        }
+    }
+
+    /// Rounds a dimension to 6 significant decimals to avoid float noise in the metadata.
+    fn dimension_value(value: f32) -> Value {
+        let value = (value as f64 * 1e6).round() / 1e6;
+        Value::from(value)
+    }
+
+    /// Creates statistics with the bounding box of the vertices.
+    fn with_bounds<'a>(vertices: impl Iterator<Item = &'a [f32; 3]>) -> ModelStats {
+        let mut stats = ModelStats {
+            bounding_box_min: [f32::MAX; 3],
+            bounding_box_max: [f32::MIN; 3],
+            ..Default::default()
+        };
+        let mut empty = true;
+        for vertex in vertices {
+            empty = false;
+            for (axis, value) in vertex.iter().enumerate() {
+                stats.bounding_box_min[axis] = stats.bounding_box_min[axis].min(*value);
+                stats.bounding_box_max[axis] = stats.bounding_box_max[axis].max(*value);
+            }
+        }
+        if empty {
+            stats.bounding_box_min = [0.0; 3];
+            stats.bounding_box_max = [0.0; 3];
+        }
+        stats
+    }
+
+    fn from_stl(path: &Path) -> Result<ModelStats, PreviewError> {
+        let mut reader = std::io::BufReader::new(std::fs::File::open(path)?);
+        let mesh = stl_io::read_stl(&mut reader)?;
+
+        let vertices: Vec<[f32; 3]> = mesh.vertices.iter().map(|v| v.0).collect();
+        let mut stats = Self::with_bounds(vertices.iter());
+        stats.triangles = mesh.faces.len();
+        stats.vertices = vertices.len();
+
+        Ok(stats)
+    }
+
+    fn from_obj(path: &Path) -> Result<ModelStats, PreviewError> {
+        let options = tobj::LoadOptions {
+            triangulate: true,
+            ignore_points: true,
+            ignore_lines: true,
+            ..Default::default()
+        };
+        let (models, materials) = tobj::load_obj(path, &options)?;
+
+        let vertices: Vec<[f32; 3]> = models
+            .iter()
+            .flat_map(|m| m.mesh.positions.chunks_exact(3))
+            .map(|p| [p[0], p[1], p[2]])
+            .collect();
+        let mut stats = Self::with_bounds(vertices.iter());
+        stats.triangles = models.iter().map(|m| m.mesh.indices.len() / 3).sum();
+        stats.vertices = vertices.len();
+
+        // a missing material library is reported by the validation, not here
+        let materials = materials.unwrap_or_default();
+        stats.materials = materials.len();
+        for material in materials {
+            let textures = [
+                material.ambient_texture,
+                material.diffuse_texture,
+                material.specular_texture,
+                material.normal_texture,
+                material.shininess_texture,
+                material.dissolve_texture,
+            ];
+            for texture in textures.into_iter().flatten() {
+                if !stats.textures.contains(&texture) {
+                    stats.textures.push(texture);
+                }
+            }
+        }
+
+        Ok(stats)
+    }
+
+    fn from_ply(path: &Path) -> Result<ModelStats, PreviewError> {
+        let ply = preview::read_ply(path)?;
+
+        let mut stats = Self::with_bounds(ply.vertices.iter());
+        stats.triangles = ply.faces.iter().map(|f| f.len().saturating_sub(2)).sum();
+        stats.vertices = ply.vertices.len();
+        // "comment TextureFile <path>" is a common convention for textured PLY files
+        stats.textures = ply
+            .comments
+            .iter()
+            .filter_map(|c| c.strip_prefix("TextureFile "))
+            .map(|t| t.trim().to_owned())
+            .collect();
+
+        Ok(stats)
+    }
+
+    fn from_gltf(path: &Path) -> Result<ModelStats, PreviewError> {
+        let gltf = gltf::Gltf::open(path)?;
+        let base = path.parent().unwrap_or(Path::new("."));
+        let buffers = gltf::import_buffers(&gltf.document, Some(base), gltf.blob.clone())?;
+
+        // like the preview, the scene is traversed so that node transforms and meshes used
+        // by several nodes are taken into account
+        let mut triangles = 0;
+        let mut vertices: Vec<[f32; 3]> = vec![];
+        let scene = gltf
+            .document
+            .default_scene()
+            .or_else(|| gltf.document.scenes().next());
+        match scene {
+            Some(scene) => {
+                for node in scene.nodes() {
+                    Self::gltf_node(&node, &IDENTITY, &buffers, &mut triangles, &mut vertices);
+                }
+            }
+            None => {
+                for mesh in gltf.document.meshes() {
+                    Self::gltf_mesh(&mesh, &IDENTITY, &buffers, &mut triangles, &mut vertices);
+                }
+            }
+        }
+
+        let mut stats = Self::with_bounds(vertices.iter());
+        stats.triangles = triangles;
+        stats.vertices = vertices.len();
+        stats.units = Some("m".to_string());
+        stats.materials = gltf.document.materials().len();
+        stats.textures = gltf
+            .document
+            .images()
+            .filter_map(|image| match image.source() {
+                gltf::image::Source::Uri { uri, .. } if !uri.starts_with("data:") => {
+                    Some(uri.to_owned())
+                }
+                _ => None,
+            })
+            .collect();
+
+        Ok(stats)
+    }
+
+    fn gltf_node(
+        node: &gltf::Node,
+        parent: &Matrix,
+        buffers: &[gltf::buffer::Data],
+        triangles: &mut usize,
+        vertices: &mut Vec<[f32; 3]>,
+    ) {
+        let transform = preview::multiply(parent, &node.transform().matrix());
+        if let Some(mesh) = node.mesh() {
+            Self::gltf_mesh(&mesh, &transform, buffers, triangles, vertices);
+        }
+        for child in node.children() {
+            Self::gltf_node(&child, &transform, buffers, triangles, vertices);
+        }
+    }
+
+    fn gltf_mesh(
+        mesh: &gltf::Mesh,
+        transform: &Matrix,
+        buffers: &[gltf::buffer::Data],
+        triangles: &mut usize,
+        vertices: &mut Vec<[f32; 3]>,
+    ) {
+        for primitive in mesh.primitives() {
+            let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+            let positions: Vec<[f32; 3]> = match reader.read_positions() {
+                Some(positions) => positions
+                    .map(|p| preview::transform_point(transform, p))
+                    .collect(),
+                None => continue,
+            };
+            if primitive.mode() == gltf::mesh::Mode::Triangles {
+                *triangles += match reader.read_indices() {
+                    Some(indices) => indices.into_u32().len() / 3,
+                    None => positions.len() / 3,
+                };
+            }
+            vertices.extend(positions);
+        }
+    }
+}
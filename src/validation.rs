@@ -0,0 +1,310 @@
+//! Validates data files before they are uploaded.
+//!
+//! The files are checked for readability and known extensions, 3D model files are parsed and the external files
+//! they reference (OBJ material libraries, textures, glTF buffers and images) are resolved.
+use crate::{
+    model::{ValidationIssue, ValidationReport, ValidationSeverity},
+    preview::{self, Mesh},
+};
+use std::{
+    collections::VecDeque,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Extensions of files that are usually uploaded together with 3D models (materials, textures, buffers).
+pub const AUXILIARY_EXTENSIONS: [&str; 11] = [
+    "mtl", "bin", "png", "jpg", "jpeg", "tga", "bmp", "tif", "tiff", "ktx2", "webp",
+];
+
+/// MTL statements that reference texture files.
+const MTL_TEXTURE_STATEMENTS: [&str; 12] = [
+    "map_Ka", "map_Kd", "map_Ks", "map_Ke", "map_Ns", "map_d", "map_bump", "map_Bump", "bump",
+    "disp", "decal", "norm",
+];
+
+/// Validates data files and the files they reference.
+///
+/// Parameters:
+/// * files: the data files
+/// * known_extension: returns true for files with a known extension. Files with other extensions are reported as warnings
+/// * include_references: if true, referenced files that exist but are not among the data files are added to them.
+///   Otherwise, they are reported as errors
+pub fn validate_files(
+    files: &[&Path],
+    known_extension: impl Fn(&Path) -> bool,
+    include_references: bool,
+) -> ValidationReport {
+    let mut report = ValidationReport {
+        files: files.iter().map(|f| f.to_path_buf()).collect(),
+        included: vec![],
+        issues: vec![],
+    };
+
+    let mut queue: VecDeque<PathBuf> = report.files.iter().cloned().collect();
+    while let Some(path) = queue.pop_front() {
+        let file = path.to_string_lossy().to_string();
+        let mut issue = |severity: ValidationSeverity, message: String| {
+            report.issues.push(ValidationIssue {
+                file: file.to_owned(),
+                severity,
+                message,
+            })
+        };
+
+        match fs::metadata(&path) {
+            Ok(metadata) if !metadata.is_file() => {
+                issue(ValidationSeverity::Error, "not a file".to_string());
+                continue;
+            }
+            Ok(metadata) if metadata.len() == 0 => {
+                issue(ValidationSeverity::Error, "the file is empty".to_string());
+                continue;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                issue(
+                    ValidationSeverity::Error,
+                    format!("cannot read the file: {}", e),
+                );
+                continue;
+            }
+        }
+        if let Err(e) = fs::File::open(&path) {
+            issue(
+                ValidationSeverity::Error,
+                format!("cannot read the file: {}", e),
+            );
+            continue;
+        }
+
+        if !known_extension(&path) && !is_auxiliary(&path) {
+            issue(
+                ValidationSeverity::Warning,
+                "unknown file extension".to_string(),
+            );
+        }
+
+        let references = match check_content(&path) {
+            Ok(references) => references,
+            Err(message) => {
+                issue(ValidationSeverity::Error, message);
+                continue;
+            }
+        };
+
+        for reference in references {
+            let referenced = resolve(&path, &reference);
+            if !referenced.is_file() {
+                issue(
+                    ValidationSeverity::Error,
+                    format!("referenced file {} not found", reference),
+                );
+                continue;
+            }
+            if Path::new(&reference).components().count() > 1 {
+                // files are uploaded into the root of the dataset
+                issue(
+                    ValidationSeverity::Warning,
+                    format!(
+                        "referenced file {} is in another directory and will be uploaded as {}",
+                        reference,
+                        referenced.file_name().unwrap_or_default().to_string_lossy()
+                    ),
+                );
+            }
+            if contains(&report.files, &referenced) {
+                continue;
+            }
+            if include_references {
+                log::trace!("Including referenced file {}", referenced.display());
+                report.files.push(referenced.to_owned());
+                report.included.push(referenced.to_owned());
+                queue.push_back(referenced);
+            } else {
+                issue(
+                    ValidationSeverity::Error,
+                    format!(
+                        "referenced file {} is not among the data files",
+                        referenced.display()
+                    ),
+                );
+            }
+        }
+    }
+
+    let mut names: Vec<(String, &PathBuf)> = report
+        .files
+        .iter()
+        .map(|f| {
+            (
+                f.file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string(),
+                f,
+            )
+        })
+        .collect();
+    names.sort();
+    for pair in names.windows(2) {
+        if pair[0].0 == pair[1].0 && !same_file(pair[0].1, pair[1].1) {
+            report.issues.push(ValidationIssue {
+                file: pair[1].1.to_string_lossy().to_string(),
+                severity: ValidationSeverity::Error,
+                message: format!(
+                    "the file name {} is used by {} as well",
+                    pair[1].0,
+                    pair[0].1.display()
+                ),
+            });
+        }
+    }
+
+    report
+}
+
+fn is_auxiliary(path: &Path) -> bool {
+    Mesh::extension(path).is_some_and(|e| AUXILIARY_EXTENSIONS.contains(&e.as_str()))
+}
+
+/// Parses the file according to its extension and returns the external files it references.
+fn check_content(path: &Path) -> Result<Vec<String>, String> {
+    match Mesh::extension(path).as_deref() {
+        Some("stl") => {
+            let mut reader =
+                std::io::BufReader::new(fs::File::open(path).map_err(|e| e.to_string())?);
+            stl_io::create_stl_reader(&mut reader)
+                .and_then(|reader| reader.collect::<Result<Vec<_>, _>>())
+                .map_err(|e| format!("invalid STL file: {}", e))?;
+            Ok(vec![])
+        }
+        Some("obj") => check_obj(path),
+        Some("mtl") => check_mtl(path),
+        Some("ply") => {
+            let ply = preview::read_ply(path).map_err(|e| e.to_string())?;
+            Ok(ply
+                .comments
+                .iter()
+                .filter_map(|c| c.strip_prefix("TextureFile "))
+                .map(|t| t.trim().to_owned())
+                .collect())
+        }
+        Some("gltf") | Some("glb") => check_gltf(path),
+        _ => Ok(vec![]),
+    }
+}
+
+fn check_obj(path: &Path) -> Result<Vec<String>, String> {
+    let options = tobj::LoadOptions {
+        triangulate: true,
+        ignore_points: true,
+        ignore_lines: true,
+        ..Default::default()
+    };
+    // the material libraries are resolved separately, so that a missing one is reported by name
+    let (models, _) = tobj::load_obj_buf(
+        &mut std::io::BufReader::new(fs::File::open(path).map_err(|e| e.to_string())?),
+        &options,
+        |_| Ok(Default::default()),
+    )
+    .map_err(|e| format!("invalid OBJ file: {}", e))?;
+    if models.iter().all(|m| m.mesh.indices.is_empty()) {
+        return Err("the OBJ file does not contain any faces".to_string());
+    }
+
+    let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    Ok(content
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("mtllib "))
+        .flat_map(|names| names.split_whitespace())
+        .map(|name| name.to_owned())
+        .collect())
+}
+
+fn check_mtl(path: &Path) -> Result<Vec<String>, String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("invalid MTL file: {}", e))?;
+    let mut references: Vec<String> = vec![];
+    for line in content.lines() {
+        let mut tokens = line.split_whitespace();
+        if tokens
+            .next()
+            .is_some_and(|statement| MTL_TEXTURE_STATEMENTS.contains(&statement))
+        {
+            // the file name is the last token, after the options
+            if let Some(texture) = tokens.last() {
+                if !references.iter().any(|r| r == texture) {
+                    references.push(texture.to_owned());
+                }
+            }
+        }
+    }
+    Ok(references)
+}
+
+fn check_gltf(path: &Path) -> Result<Vec<String>, String> {
+    let gltf = gltf::Gltf::open(path).map_err(|e| format!("invalid glTF file: {}", e))?;
+    let mut references: Vec<String> = vec![];
+
+    for buffer in gltf.document.buffers() {
+        match buffer.source() {
+            gltf::buffer::Source::Uri(uri) if !uri.starts_with("data:") => {
+                let uri = decode_uri(uri);
+                let file = resolve(path, &uri);
+                if let Ok(metadata) = fs::metadata(&file) {
+                    if (metadata.len() as usize) < buffer.length() {
+                        return Err(format!(
+                            "buffer {} is shorter than the declared {} bytes",
+                            uri,
+                            buffer.length()
+                        ));
+                    }
+                }
+                references.push(uri);
+            }
+            gltf::buffer::Source::Bin if gltf.blob.is_none() => {
+                return Err("the binary buffer is missing".to_string());
+            }
+            _ => {}
+        }
+    }
+    for image in gltf.document.images() {
+        if let gltf::image::Source::Uri { uri, .. } = image.source() {
+            if uri.starts_with("data:") {
+                continue;
+            }
+            let uri = decode_uri(uri);
+            if !references.contains(&uri) {
+                references.push(uri);
+            }
+        }
+    }
+
+    Ok(references)
+}
+
+/// Decodes the percent-encoded characters of a glTF URI (e.g. my%20texture.png).
+/// URIs that do not decode to UTF-8 are kept as they are.
+fn decode_uri(uri: &str) -> String {
+    urlencoding::decode(uri)
+        .map(|decoded| decoded.into_owned())
+        .unwrap_or_else(|_| uri.to_owned())
+}
+
+/// Resolves a reference relative to the directory of the referencing file.
+fn resolve(path: &Path, reference: &str) -> PathBuf {
+    path.parent()
+        .unwrap_or(Path::new("."))
+        .join(reference.replace('\\', "/"))
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+fn contains(files: &[PathBuf], path: &Path) -> bool {
+    files.iter().any(|f| same_file(f, path))
+}